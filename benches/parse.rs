@@ -0,0 +1,62 @@
+//! Compares the integer extraction of `advent_of_code::parse` with the
+//! `split_whitespace().map(str::parse)` idiom that the solutions used, e.g. day 02's reports,
+//! on a generated input of lines with four numbers each (`12 7 -3 4`).
+use std::hint::black_box;
+
+use advent_of_code::fuzz::Rng;
+use advent_of_code::parse::{ints, ints_in};
+use criterion::{criterion_group, criterion_main, Criterion};
+
+fn input() -> String {
+    let mut rng = Rng::new(0);
+    (0..10_000)
+        .map(|_| {
+            format!(
+                "{} {} {} {}\n",
+                rng.range(0..=100),
+                rng.range(0..=102),
+                rng.range(-100..=100),
+                rng.range(-100..=100)
+            )
+        })
+        .collect()
+}
+
+fn split_line(line: &str) -> [i64; 4] {
+    let numbers: Vec<i64> = line
+        .split_whitespace()
+        .map(str::parse)
+        .collect::<Result<_, _>>()
+        .unwrap();
+    numbers.try_into().unwrap()
+}
+
+fn bench_parse(c: &mut Criterion) {
+    let input = input();
+    let mut group = c.benchmark_group("parse ints");
+
+    group.bench_function("split_whitespace and parse", |b| {
+        b.iter(|| {
+            black_box(&input)
+                .lines()
+                .map(split_line)
+                .collect::<Vec<_>>()
+        })
+    });
+    group.bench_function("ints per line", |b| {
+        b.iter(|| {
+            black_box(&input)
+                .lines()
+                .map(|line| ints::<4>(line).unwrap())
+                .collect::<Vec<_>>()
+        })
+    });
+    group.bench_function("ints_in whole input", |b| {
+        b.iter(|| ints_in::<i64>(black_box(&input)).sum::<i64>())
+    });
+
+    group.finish();
+}
+
+criterion_group!(benches, bench_parse);
+criterion_main!(benches);
@@ -0,0 +1,36 @@
+//! Benchmarks the parser and both parts of every day with criterion.
+//!
+//! Days without an input in `data/inputs` are skipped. Reports are written to `target/criterion`.
+use std::fs;
+use std::hint::black_box;
+
+use advent_of_code::days::solutions;
+use criterion::{criterion_group, criterion_main, Criterion};
+
+fn bench_days(c: &mut Criterion) {
+    for solution in solutions() {
+        let day = solution.day;
+        let Ok(input) = fs::read_to_string(format!("data/inputs/{day}.txt")) else {
+            continue;
+        };
+
+        let mut group = c.benchmark_group(format!("day {day}"));
+
+        if let Some(parse) = &solution.parse {
+            group.bench_function("parse", |b| b.iter(|| parse(black_box(&input))));
+        }
+
+        for (part, func) in solution.parts.iter().enumerate() {
+            if let Some(func) = func {
+                group.bench_function(format!("part {}", part + 1), |b| {
+                    b.iter(|| func(black_box(&input)))
+                });
+            }
+        }
+
+        group.finish();
+    }
+}
+
+criterion_group!(benches, bench_days);
+criterion_main!(benches);
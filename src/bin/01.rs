@@ -2,7 +2,7 @@ advent_of_code::solution!(1);
 use itertools::Itertools;
 use num::ToPrimitive;
 
-fn parse_input(input: &str) -> [Vec<usize>; 2] {
+pub fn parse_input(input: &str) -> [Vec<usize>; 2] {
     let mut lists = [vec![], vec![]];
     for (i, item) in input.split_whitespace().enumerate() {
         lists[i % 2].push(item.parse().unwrap());
@@ -17,7 +17,7 @@ pub fn part_one(input: &str) -> Option<u64> {
     right.sort();
     let result = left
         .into_iter()
-        .zip(right.into_iter())
+        .zip(right)
         .map(|(l, r)| l.abs_diff(r))
         .sum::<usize>();
     result.to_u64()
@@ -1,3 +1,4 @@
+use advent_of_code::fuzz::{Rng, Target};
 use itertools::Itertools;
 use num::ToPrimitive;
 
@@ -10,7 +11,7 @@ fn report_check(report: &[i64]) -> bool {
         && report.windows(2).all(|r| r[0].abs_diff(r[1]) <= 3)
 }
 
-fn parse_reports(input: &str) -> Vec<Vec<i64>> {
+pub fn parse_reports(input: &str) -> Vec<Vec<i64>> {
     input
         .split_whitespace()
         .map(|data| data.parse().unwrap())
@@ -48,7 +49,60 @@ pub fn part_two(input: &str) -> Option<u64> {
 
     reports
         .into_iter()
-        .filter(|report| report_check_with_tolerance(&report))
+        .filter(|report| report_check_with_tolerance(report))
+        .count()
+        .to_u64()
+}
+
+pub fn fuzz_targets() -> Vec<Target> {
+    vec![Target::new(
+        "part two",
+        generate_reports,
+        part_two_reference,
+        part_two,
+    )]
+}
+
+/// Generates reports of five levels, half of them with a single level replaced at random.
+fn generate_reports(rng: &mut Rng) -> String {
+    (0..rng.range(1..=20))
+        .map(|_| {
+            let step = *rng.choose(&[-1, 1]);
+            let mut level = rng.range(1..=50);
+            let mut levels = vec![];
+            for _ in 0..5 {
+                levels.push(level);
+                level += step * rng.range(0..=4);
+            }
+            if rng.chance(0.5) {
+                let i = rng.below(levels.len());
+                levels[i] = rng.range(1..=60);
+            }
+            levels.iter().join(" ")
+        })
+        .join("\n")
+}
+
+/// Tries every removal of a single level, one line at a time.
+fn part_two_reference(input: &str) -> Option<u64> {
+    let is_safe = |levels: &[i64]| {
+        let diffs = levels.windows(2).map(|w| w[1] - w[0]).collect_vec();
+        diffs.iter().all(|d| (1..=3).contains(d)) || diffs.iter().all(|d| (-3..=-1).contains(d))
+    };
+
+    input
+        .lines()
+        .filter(|line| {
+            let levels = line
+                .split_whitespace()
+                .map(|x| x.parse::<i64>().unwrap())
+                .collect_vec();
+            (0..levels.len()).any(|i| {
+                let mut rest = levels.clone();
+                rest.remove(i);
+                is_safe(&rest)
+            })
+        })
         .count()
         .to_u64()
 }
@@ -1,84 +1,29 @@
+use advent_of_code::grid::{Grid, Pos};
+use advent_of_code::point::Dir8;
 use itertools::Itertools;
 
 advent_of_code::solution!(4);
 
 const XMAS: [char; 4] = ['X', 'M', 'A', 'S'];
 
-enum Dir {
-    U,
-    D,
-    L,
-    R,
-    UL,
-    DL,
-    UR,
-    DR,
-}
-const ALL_DIR: [Dir; 8] = [
-    Dir::U,
-    Dir::D,
-    Dir::L,
-    Dir::R,
-    Dir::UL,
-    Dir::DL,
-    Dir::UR,
-    Dir::DR,
-];
-
-impl Dir {
-    fn advance(&self, (i, j): (usize, usize)) -> (usize, usize) {
-        match self {
-            Dir::U => (i.wrapping_sub(1), j),
-            Dir::D => (i + 1, j),
-            Dir::L => (i, j.wrapping_sub(1)),
-            Dir::R => (i, j + 1),
-            Dir::UL => Self::advance(&Self::U, Self::advance(&Self::L, (i, j))),
-            Dir::DL => Self::advance(&Self::D, Self::advance(&Self::L, (i, j))),
-            Dir::UR => Self::advance(&Self::U, Self::advance(&Self::R, (i, j))),
-            Dir::DR => Self::advance(&Self::D, Self::advance(&Self::R, (i, j))),
-        }
-    }
-}
-
-fn check(grid: &Vec<Vec<char>>, (i, j): (usize, usize), dir: Dir, depth: usize) -> bool {
+fn check(grid: &Grid<char>, pos: Pos, dir: Dir8, depth: usize) -> bool {
     if depth == XMAS.len() {
         return true;
     }
-    let (i, j) = dir.advance((i, j));
-    // dbg!(i, j);
-    if i >= grid.len() || j >= grid[0].len() {
-        return false;
+    match grid.step(pos, dir) {
+        Some(next) if grid[next] == XMAS[depth] => check(grid, next, dir, depth + 1),
+        _ => false,
     }
-    if grid[i][j] == XMAS[depth] {
-        return check(grid, (i, j), dir, depth + 1);
-    }
-    false
 }
 
 pub fn part_one(input: &str) -> Option<u64> {
-    // dbg!(input);
-    let grid = input
-        .lines()
-        .map(|line| line.chars().collect_vec())
-        .collect_vec();
-    let m = grid.len();
-    let n = grid[0].len();
-    dbg!(m, n);
-    let mut count = 0;
-    for (i, row) in grid.iter().enumerate() {
-        for (j, ch) in row.iter().enumerate() {
-            if ch != &'X' {
-                continue;
-            }
-            // dbg!(i, j);
-            for dir in ALL_DIR {
-                if check(&grid, (i, j), dir, 1) {
-                    count += 1;
-                }
-            }
-        }
-    }
-    Some(count)
+    let grid: Grid<char> = input.parse().ok()?;
+    let count = grid
+        .find_all(&'X')
+        .flat_map(|pos| Dir8::ALL.map(|dir| check(&grid, pos, dir, 1)))
+        .filter(|&found| found)
+        .count();
+    Some(count as u64)
 }
 
 pub fn part_two(input: &str) -> Option<u64> {
@@ -88,7 +33,7 @@ pub fn part_two(input: &str) -> Option<u64> {
         .collect_vec();
     let m = grid.len();
     let n = grid[0].len();
-    dbg!(m, n);
+    // dbg!(m, n);
     let mut count = 0;
     for (i, row) in grid[..m - 1].iter().enumerate().skip(1) {
         for (j, ch) in row[..n - 1].iter().enumerate().skip(1) {
@@ -1,4 +1,7 @@
-use std::collections::{HashMap, HashSet};
+use std::{
+    cmp::Reverse,
+    collections::{HashMap, HashSet},
+};
 
 use itertools::Itertools;
 
@@ -7,7 +10,7 @@ advent_of_code::solution!(5);
 fn part_one_check_order(update: &[u64], rules: &HashMap<u64, HashSet<u64>>) -> bool {
     let mut table = rules.get(&update[0]).cloned().unwrap_or(HashSet::new());
     // Skipping the first and last item
-    let mut iter = update.into_iter().skip(1);
+    let mut iter = update.iter().skip(1);
     let last = iter.next_back().unwrap();
 
     for update in iter {
@@ -15,7 +18,7 @@ fn part_one_check_order(update: &[u64], rules: &HashMap<u64, HashSet<u64>>) -> b
             return false;
         }
         if let Some(rule) = rules.get(update) {
-            table = table.intersection(&rule).cloned().collect();
+            table = table.intersection(rule).cloned().collect();
         } else {
             return false;
         }
@@ -23,12 +26,14 @@ fn part_one_check_order(update: &[u64], rules: &HashMap<u64, HashSet<u64>>) -> b
     table.contains(last)
 }
 
-fn parse(input: &str) -> Option<(HashMap<u64, HashSet<u64>>, Vec<Vec<u64>>)> {
+pub type Rules = HashMap<u64, HashSet<u64>>;
+
+pub fn parse(input: &str) -> Option<(Rules, Vec<Vec<u64>>)> {
     let (rules_input, updates_input) = input.split("\n\n").collect_tuple()?;
 
     let mut rules = HashMap::new();
     for line in rules_input.lines().take_while(|line| line != &"") {
-        let (before, after) = dbg!(line)
+        let (before, after) = line
             .split("|")
             .filter_map(|val| val.parse::<u64>().ok())
             .collect_tuple()?;
@@ -50,7 +55,7 @@ pub fn part_one(input: &str) -> Option<u64> {
     let mut sum = 0;
     for update in updates {
         if part_one_check_order(&update, &rules) {
-            dbg!(&update);
+            // dbg!(&update);
             sum += update[update.len() / 2];
         }
     }
@@ -60,12 +65,12 @@ pub fn part_one(input: &str) -> Option<u64> {
 fn part_two_helper(update: Vec<u64>, rules: &HashMap<u64, HashSet<u64>>) -> Vec<u64> {
     let updates = update.into_iter().collect::<HashSet<u64>>();
     let purned_rules = rules
-        .into_iter()
+        .iter()
         .filter(|(key, _)| updates.contains(key))
         .map(|(&key, val)| (key, val.intersection(&updates).count()))
         .collect::<HashMap<u64, _>>();
     let mut tmp = purned_rules.into_iter().collect_vec();
-    tmp.sort_by(|a, b| b.1.cmp(&a.1));
+    tmp.sort_by_key(|a| Reverse(a.1));
     // dbg!(&tmp);
     tmp.into_iter().map(|x| x.0).collect_vec()
 }
@@ -80,7 +85,7 @@ pub fn part_two(input: &str) -> Option<u64> {
             continue;
         }
         let new_items = part_two_helper(update, &rules);
-        dbg!(&new_items);
+        // dbg!(&new_items);
         sum += new_items[new_items.len() / 2];
     }
     Some(sum)
@@ -1,119 +1,94 @@
-use std::{collections::HashSet, marker::PhantomData};
-
-use itertools::Itertools;
+use advent_of_code::bitset::BitGrid;
+use advent_of_code::parse::grid_of;
+use advent_of_code::point::{Dir4, Point};
 use num::ToPrimitive;
 
 advent_of_code::solution!(6);
 
-#[derive(Debug, Clone, Eq, PartialEq, Default)]
+#[derive(Debug, Clone, Copy, Eq, PartialEq)]
 enum Cell {
-    #[default]
     Empty,
-    Visited([bool; 4]),
     Obstacle,
 }
 
-const UP: (usize, usize) = (usize::MAX, 0);
-const DOWN: (usize, usize) = (1, 0);
-const LEFT: (usize, usize) = (0, usize::MAX);
-const RIGHT: (usize, usize) = (0, 1);
-
-fn dir2idx(dir: &(usize, usize)) -> usize {
-    [UP, DOWN, LEFT, RIGHT]
-        .iter()
-        .position(|x| x == dir)
-        .unwrap()
-}
-
-#[derive(Debug, Clone, Default)]
+#[derive(Debug, Clone)]
 struct Helper {
+    start: (usize, usize),
     guard: (usize, usize),
-    dir: (usize, usize),
+    dir: Dir4,
     map: Vec<Vec<Cell>>,
+    /// The tiles the guard entered, one set per direction it was facing.
+    visited: [BitGrid; 4],
     has_loop: bool,
 }
 
 impl Helper {
-    fn rotate(&self) -> (usize, usize) {
-        match self.dir {
-            UP => RIGHT,
-            RIGHT => DOWN,
-            DOWN => LEFT,
-            LEFT => UP,
-            (_, _) => unreachable!(),
-        }
-    }
-
-    fn next_step(&self) -> (usize, usize) {
-        (
-            self.guard.0.wrapping_add(self.dir.0),
-            self.guard.1.wrapping_add(self.dir.1),
-        )
+    fn next_step(&self) -> Option<(usize, usize)> {
+        Point::from(self.guard)
+            .step(self.dir)
+            .to_pos(self.map[0].len(), self.map.len())
     }
 
     fn new(input: &str) -> Self {
-        let mut guard = None;
-        let dir = (usize::MAX, 0);
-        let rows = input.lines().collect_vec();
-        assert!(rows.len() < usize::MAX && rows[0].len() < usize::MAX);
-        let mut map = vec![vec![Cell::Empty; rows[0].len()]; rows.len()];
-        for (i, row) in rows.into_iter().enumerate() {
-            for (j, cell) in row.chars().enumerate() {
-                match cell {
-                    '.' => (),
-                    '#' => map[i][j] = Cell::Obstacle,
-                    '^' => {
-                        map[i][j] = Cell::Visited([true, false, false, false]);
-                        let _ = guard.insert((i, j));
-                    }
-                    _ => unreachable!(),
-                }
-            }
-        }
-        Self {
-            guard: guard.unwrap(),
-            dir,
+        let map = grid_of(input, "one of `.#^`", |cell| match cell {
+            '.' | '^' => Some(Cell::Empty),
+            '#' => Some(Cell::Obstacle),
+            _ => None,
+        })
+        .unwrap();
+        let start = input
+            .lines()
+            .enumerate()
+            .find_map(|(i, line)| line.find('^').map(|j| (i, j)))
+            .unwrap();
+        let (width, height) = (map[0].len(), map.len());
+        let mut helper = Self {
+            start,
+            guard: start,
+            dir: Dir4::North,
             map,
-            ..Default::default()
-        }
+            visited: std::array::from_fn(|_| BitGrid::new(width, height)),
+            has_loop: false,
+        };
+        helper.reset();
+        helper
+    }
+
+    /// Puts the guard back at the start, forgetting where it walked.
+    fn reset(&mut self) {
+        self.guard = self.start;
+        self.dir = Dir4::North;
+        self.visited.iter_mut().for_each(BitGrid::clear);
+        self.visited[Dir4::North.index()].insert(self.start);
+        self.has_loop = false;
     }
 
     fn update(&mut self) -> bool {
-        let next = self.next_step();
-        if next.0 >= self.map.len() || next.1 >= self.map[0].len() {
+        let Some(next) = self.next_step() else {
             return false;
-        }
+        };
         match self.map[next.0][next.1] {
             Cell::Obstacle => {
-                self.dir = self.rotate();
+                self.dir = self.dir.turn_right();
             }
             Cell::Empty => {
-                let mut visited = [false; 4];
-                visited[dir2idx(&self.dir)] = true;
-                self.map[next.0][next.1] = Cell::Visited(visited);
-                self.guard = next;
-            }
-            Cell::Visited(ref mut visited) => {
-                if visited[dir2idx(&self.dir)] {
+                if !self.visited[self.dir.index()].insert(next) {
                     self.has_loop = true;
                     return false;
                 }
-                visited[dir2idx(&self.dir)] = true;
                 self.guard = next;
             }
         }
         true
     }
 
-    fn count(self) -> usize {
-        self.map
-            .into_iter()
-            .flatten()
-            .filter(|cell| match cell {
-                Cell::Visited(_) => true,
-                _ => false,
-            })
-            .count()
+    /// Returns the tiles the guard entered in any direction.
+    fn tiles(&self) -> BitGrid {
+        let mut tiles = self.visited[0].clone();
+        for visited in &self.visited[1..] {
+            tiles.union_with(visited);
+        }
+        tiles
     }
 }
 
@@ -121,7 +96,7 @@ pub fn part_one(input: &str) -> Option<u64> {
     // dbg!(input);
     let mut helper = Helper::new(input);
     while helper.update() {}
-    helper.count().to_u64()
+    helper.tiles().len().to_u64()
 }
 
 // Brute force
@@ -130,19 +105,14 @@ pub fn part_two(input: &str) -> Option<u64> {
     while helper.update() {}
 
     let mut count = 0;
-    for (i, row) in helper.map.iter().enumerate() {
-        for (j, cell) in row.iter().enumerate() {
-            match cell {
-                Cell::Visited(_) => {}
-                _ => continue,
-            }
-            let mut tmp = Helper::new(input);
-            tmp.map[i][j] = Cell::Obstacle;
-            while tmp.update() {}
-            if tmp.has_loop == true {
-                count += 1;
-            }
+    for (i, j) in helper.tiles().iter() {
+        helper.map[i][j] = Cell::Obstacle;
+        helper.reset();
+        while helper.update() {}
+        if helper.has_loop {
+            count += 1;
         }
+        helper.map[i][j] = Cell::Empty;
     }
     count.to_u64()
 }
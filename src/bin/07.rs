@@ -1,3 +1,4 @@
+use advent_of_code::fuzz::{Rng, Target};
 use itertools::Itertools;
 use num::ToPrimitive;
 
@@ -90,6 +91,80 @@ pub fn part_two(input: &str) -> Option<u64> {
     sum.to_u64()
 }
 
+pub fn fuzz_targets() -> Vec<Target> {
+    vec![
+        Target::new(
+            "part one",
+            generate_equations,
+            |input: &str| calibration_reference(input, false),
+            part_one,
+        ),
+        Target::new(
+            "part two",
+            generate_equations,
+            |input: &str| calibration_reference(input, true),
+            part_two,
+        ),
+    ]
+}
+
+/// Generates equations that are solvable about half of the time.
+fn generate_equations(rng: &mut Rng) -> String {
+    (0..rng.range(1..=10))
+        .map(|_| {
+            let values = (0..rng.range(2..=6))
+                .map(|_| rng.range(1..=20) as u64)
+                .collect_vec();
+            let target = if rng.chance(0.5) {
+                values[1..]
+                    .iter()
+                    .fold(values[0], |acc, &x| match rng.below(3) {
+                        0 => acc + x,
+                        1 => acc * x,
+                        _ => acc * min_10_pow(&x) + x,
+                    })
+            } else {
+                rng.range(1..=1000) as u64
+            };
+            format!("{target}: {}", values.iter().join(" "))
+        })
+        .join("\n")
+}
+
+/// Tries every operator recursively, from left to right.
+fn calibration_reference(input: &str, with_concat: bool) -> Option<u64> {
+    fn is_solvable(target: u64, acc: u64, rest: &[u64], with_concat: bool) -> bool {
+        match rest.split_first() {
+            None => acc == target,
+            Some((&x, rest)) => {
+                is_solvable(target, acc + x, rest, with_concat)
+                    || is_solvable(target, acc * x, rest, with_concat)
+                    || (with_concat
+                        && is_solvable(
+                            target,
+                            format!("{acc}{x}").parse().unwrap(),
+                            rest,
+                            with_concat,
+                        ))
+            }
+        }
+    }
+
+    let mut sum = 0;
+    for line in input.lines() {
+        let (target, values) = line.split_once(": ").unwrap();
+        let target = target.parse().unwrap();
+        let values = values
+            .split_whitespace()
+            .map(|s| s.parse().unwrap())
+            .collect_vec();
+        if is_solvable(target, values[0], &values[1..], with_concat) {
+            sum += target;
+        }
+    }
+    Some(sum)
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -1,6 +1,5 @@
 use std::collections::VecDeque;
 
-use itertools::Itertools;
 use num::ToPrimitive;
 
 advent_of_code::solution!(9);
@@ -106,12 +105,12 @@ pub fn part_two(input: &str) -> Option<u64> {
         if i % 2 == 0 {
             disk.push(Frag::File {
                 id: i.to_u64().unwrap() / 2,
-                size: size,
+                size,
             });
         } else {
             disk.push(Frag::Space {
                 content: vec![],
-                size: size,
+                size,
             });
         }
     }
@@ -119,7 +118,7 @@ pub fn part_two(input: &str) -> Option<u64> {
     for i in (0..disk.len()).rev().step_by(2) {
         let (search_space, target) = disk.split_at_mut(i);
         let target_size = match &target[0] {
-            Frag::File { id: _, size } => size.clone(),
+            Frag::File { id: _, size } => *size,
             Frag::Space {
                 content: _,
                 size: _,
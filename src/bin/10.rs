@@ -1,51 +1,30 @@
-use std::collections::HashSet;
-
-use itertools::{iproduct, Itertools};
+use advent_of_code::bitset::BitGrid;
+use advent_of_code::grid::{Grid, Pos};
+use advent_of_code::memo::Memo;
 use num::ToPrimitive;
 
 advent_of_code::solution!(10);
 
-pub fn parse_grid(input: &str) -> Vec<Vec<usize>> {
-    input
-        .lines()
-        .map(|line| {
-            line.chars()
-                .map(|item| item.to_digit(10).unwrap() as usize)
-                .collect_vec()
-        })
-        .collect_vec()
+pub fn parse_grid(input: &str) -> Grid<u32> {
+    Grid::parse(input, "a height from 0 to 9", |c| c.to_digit(10)).unwrap()
 }
 
 pub fn part_one(input: &str) -> Option<u64> {
-    // dbg!(input);
     let grid = parse_grid(input);
-    let m = grid.len();
-    let n = grid[0].len();
 
     let mut count = 0;
-    for (i, j) in iproduct!(0..m, 0..n) {
-        if grid[i][j] != 0 {
-            continue;
-        }
-        let mut stack = vec![(i, j)];
-        let mut visited = HashSet::new();
-        visited.insert((i, j));
-        while let Some((i, j)) = stack.pop() {
-            if grid[i][j] == 9 {
+    let mut visited = BitGrid::new(grid.width(), grid.height());
+    for start in grid.find_all(&0) {
+        let mut stack = vec![start];
+        visited.clear();
+        visited.insert(start);
+        while let Some(pos) = stack.pop() {
+            if grid[pos] == 9 {
                 count += 1;
             }
-            for offset in [1, usize::MAX] {
-                let x = i.wrapping_add(offset);
-                if x < m && grid[x][j] == grid[i][j] + 1 {
-                    if visited.insert((x, j)) {
-                        stack.push((x, j));
-                    }
-                }
-                let y = j.wrapping_add(offset);
-                if y < n && grid[i][y] == grid[i][j] + 1 {
-                    if visited.insert((i, y)) {
-                        stack.push((i, y));
-                    }
+            for next in grid.neighbors4(pos) {
+                if grid[next] == grid[pos] + 1 && visited.insert(next) {
+                    stack.push(next);
                 }
             }
         }
@@ -53,49 +32,27 @@ pub fn part_one(input: &str) -> Option<u64> {
     count.to_u64()
 }
 
-pub fn part_two_helper(
-    target: usize,
-    i: usize,
-    j: usize,
-    grid: &Vec<Vec<usize>>,
-    dp: &mut Vec<Vec<Option<u64>>>,
-) -> Option<u64> {
-    if i >= grid.len() || j >= grid[0].len() || target != grid[i][j] {
-        return None;
-    }
-    if dp[i][j].is_some() {
-        return dp[i][j];
-    }
-    let mut sum = 0;
-    if target == grid[i][j] {
-        sum += part_two_helper(target + 1, i.wrapping_add(1), j, grid, dp).unwrap_or(0);
-        sum += part_two_helper(target + 1, i.wrapping_sub(1), j, grid, dp).unwrap_or(0);
-        sum += part_two_helper(target + 1, i, j.wrapping_add(1), grid, dp).unwrap_or(0);
-        sum += part_two_helper(target + 1, i, j.wrapping_sub(1), grid, dp).unwrap_or(0);
-    }
-    let _ = dp[i][j].insert(sum);
-    return dp[i][j];
+/// Counts the trails from a position up to a height of 9.
+fn trails(grid: &Grid<u32>) -> Memo<'_, Pos, u64> {
+    Memo::new(move |memo, &pos| {
+        if grid[pos] == 9 {
+            return 1;
+        }
+        grid.neighbors4(pos)
+            .filter(|&next| grid[next] == grid[pos] + 1)
+            .map(|next| memo.get(next))
+            .sum()
+    })
 }
 
 pub fn part_two(input: &str) -> Option<u64> {
     let grid = parse_grid(input);
-    let m = grid.len();
-    let n = grid[0].len();
-    let mut dp: Vec<Vec<Option<u64>>> = grid
-        .iter()
-        .map(|row| {
-            row.iter()
-                .map(|val| if val == &9 { Some(1) } else { None })
-                .collect_vec()
-        })
-        .collect_vec();
+    let mut trails = trails(&grid);
 
-    let mut count = 0;
-    for (i, j) in iproduct!(0..m, 0..n) {
-        count += part_two_helper(0, i, j, &grid, &mut dp).unwrap_or(0);
-    }
-    // dbg!(dp);
-    count.to_u64()
+    grid.find_all(&0)
+        .map(|start| trails.get(start))
+        .sum::<u64>()
+        .to_u64()
 }
 
 #[cfg(test)]
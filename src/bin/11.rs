@@ -1,5 +1,5 @@
-use std::collections::HashMap;
-
+use advent_of_code::fuzz::{Rng, Target};
+use advent_of_code::memo::Memo;
 use itertools::Itertools;
 use num::ToPrimitive;
 
@@ -19,7 +19,7 @@ fn apply_rules(val: u64) -> impl IntoIterator<Item = u64> {
         return vec![1].into_iter();
     }
     let digit_count = count_digit(val);
-    if digit_count % 2 == 0 {
+    if digit_count.is_multiple_of(2) {
         let mask = 10u64.pow(digit_count / 2);
         return vec![val / mask, val % mask].into_iter();
     }
@@ -39,31 +39,17 @@ pub fn part_one(input: &str) -> Option<u64> {
     stones.len().to_u64()
 }
 
-fn helper(depth: usize, val: u64, dp: &mut HashMap<(usize, u64), u64>) -> u64 {
-    if depth == 0 {
-        return 1;
-    }
-    if let Some(result) = dp.get(&(depth, val)) {
-        return result.clone();
-    }
-    if val == 0 {
-        let result = helper(depth - 1, 1, dp);
-        dp.entry((depth, val)).or_insert(result);
-        return result;
-    }
-    let digit_count = count_digit(val);
-    if digit_count % 2 == 0 {
-        let mask = 10u64.pow(digit_count / 2);
-        let result = [val / mask, val % mask]
+/// Counts the stones that a stone becomes after some blinks, keyed by `(blinks, stone)`.
+fn blinks() -> Memo<'static, (usize, u64), u64> {
+    Memo::new(|memo, &(depth, val)| {
+        if depth == 0 {
+            return 1;
+        }
+        apply_rules(val)
             .into_iter()
-            .map(|next| helper(depth - 1, next, dp))
-            .sum();
-        dp.entry((depth, val)).or_insert(result);
-        return result;
-    }
-    let result = helper(depth - 1, val * 2024, dp);
-    dp.entry((depth, val)).or_insert(result);
-    return result;
+            .map(|next| memo.get((depth - 1, next)))
+            .sum()
+    })
 }
 
 pub fn part_two(input: &str) -> Option<u64> {
@@ -72,14 +58,35 @@ pub fn part_two(input: &str) -> Option<u64> {
         .map(|s| s.parse::<u64>().unwrap())
         .collect_vec();
     // dbg!(&stones);
-    let mut dp = HashMap::new();
+    let mut blinks = blinks();
     let count = stones
         .into_iter()
-        .map(|stone| helper(75, stone, &mut dp))
+        .map(|stone| blinks.get((75, stone)))
         .sum();
     Some(count)
 }
 
+pub fn fuzz_targets() -> Vec<Target> {
+    // `part_one` expands every stone, the memoized `blinks` must count the same number of stones.
+    vec![Target::new(
+        "memoized blinks",
+        generate_stones,
+        part_one,
+        |input: &str| {
+            let mut blinks = blinks();
+            input
+                .split_whitespace()
+                .map(|s| blinks.get((25, s.parse().unwrap())))
+                .sum::<u64>()
+                .to_u64()
+        },
+    )]
+}
+
+fn generate_stones(rng: &mut Rng) -> String {
+    (0..rng.range(1..=3)).map(|_| rng.range(0..=9999)).join(" ")
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -1,10 +1,11 @@
+use advent_of_code::bitset::BitGrid;
 use itertools::{iproduct, Itertools};
 use num::ToPrimitive;
-use std::collections::{BTreeMap, BTreeSet, HashSet};
+use std::collections::{BTreeMap, BTreeSet};
 
 advent_of_code::solution!(12);
 
-fn get_neighbor((i, j): (usize, usize), grid: &Vec<Vec<char>>) -> Vec<(usize, usize)> {
+fn get_neighbor((i, j): (usize, usize), grid: &[Vec<char>]) -> Vec<(usize, usize)> {
     let mut result = vec![];
     if i != 0 {
         result.push((i - 1, j));
@@ -21,27 +22,25 @@ fn get_neighbor((i, j): (usize, usize), grid: &Vec<Vec<char>>) -> Vec<(usize, us
     result
 }
 
-fn dfs(root: (usize, usize), grid: &Vec<Vec<char>>) -> HashSet<(usize, usize)> {
+fn dfs(root: (usize, usize), grid: &[Vec<char>]) -> BitGrid {
     let mut stack = vec![root];
-    let mut visited = HashSet::new();
+    let mut visited = BitGrid::new(grid[0].len(), grid.len());
     visited.insert(root);
     while let Some((i, j)) = stack.pop() {
         let neighbors = get_neighbor((i, j), grid);
         for (x, y) in neighbors {
-            if grid[x][y] == grid[i][j] {
-                if visited.insert((x, y)) {
-                    stack.push((x, y));
-                }
+            if grid[x][y] == grid[i][j] && visited.insert((x, y)) {
+                stack.push((x, y));
             }
         }
     }
     visited
 }
 
-fn count_edge(regions: &HashSet<(usize, usize)>) -> u64 {
+fn count_edge(regions: &BitGrid) -> u64 {
     let mut rows = BTreeMap::new();
     let mut columns = BTreeMap::new();
-    for (i, j) in regions {
+    for (i, j) in regions.iter() {
         rows.entry(i).or_insert(BTreeSet::new()).insert(j);
         columns.entry(j).or_insert(BTreeSet::new()).insert(i);
     }
@@ -58,7 +57,7 @@ fn count_edge(regions: &HashSet<(usize, usize)>) -> u64 {
 
 fn count_group<'a>(iter: impl IntoIterator<Item = &'a usize>) -> usize {
     let value = iter.into_iter().collect_vec();
-    if value.len() == 0 {
+    if value.is_empty() {
         return 0;
     }
     value
@@ -67,10 +66,10 @@ fn count_group<'a>(iter: impl IntoIterator<Item = &'a usize>) -> usize {
         .fold(1, |acc, (a, b)| acc + (b - a != 1) as usize)
 }
 
-fn count_edge_group(regions: &HashSet<(usize, usize)>) -> u64 {
+fn count_edge_group(regions: &BitGrid) -> u64 {
     let mut rows = BTreeMap::new();
     let mut columns = BTreeMap::new();
-    for &(i, j) in regions {
+    for (i, j) in regions.iter() {
         rows.entry(i).or_insert(BTreeSet::new()).insert(j);
         columns.entry(j).or_insert(BTreeSet::new()).insert(i);
     }
@@ -102,9 +101,9 @@ pub fn part_one(input: &str) -> Option<u64> {
         let region = dfs((i, j), &grid);
         let area = region.len() as u64;
         let edge = count_edge(&region);
-        dbg!(grid[i][j], area, edge, area * edge);
+        // dbg!(grid[i][j], area, edge, area * edge);
         sum += area * edge;
-        for (x, y) in region {
+        for (x, y) in region.iter() {
             grid[x][y] = '.';
         }
     }
@@ -127,9 +126,9 @@ pub fn part_two(input: &str) -> Option<u64> {
         let region = dfs((i, j), &grid);
         let area = region.len() as u64;
         let edge = count_edge_group(&region);
-        dbg!(grid[i][j], area, edge, area * edge);
+        // dbg!(grid[i][j], area, edge, area * edge);
         sum += area * edge;
-        for (x, y) in region {
+        for (x, y) in region.iter() {
             grid[x][y] = '.';
         }
     }
@@ -1,5 +1,7 @@
+use advent_of_code::math::solve_2x2;
+use advent_of_code::parse::ints;
 use itertools::Itertools;
-use num::{integer::gcd, ToPrimitive};
+use num::ToPrimitive;
 
 advent_of_code::solution!(13);
 
@@ -31,40 +33,19 @@ impl Machine {
     }
 
     fn parse_x_y(line: &str) -> (i64, i64) {
-        let x_start = line.find('X').unwrap() + 2;
-        let x_end = line.find(',').unwrap();
-        let y_start = line.find('Y').unwrap() + 2;
-        (
-            line[x_start..x_end].parse().unwrap(),
-            line[y_start..].parse().unwrap(),
-        )
+        let [x, y] = ints(line).unwrap();
+        (x, y)
     }
 
     fn solve_press_count(&self) -> Option<(i64, i64)> {
-        let a = self.button_a.0;
-        let b = self.button_b.0;
-        let c = self.button_a.1;
-        let d = self.button_b.1;
-        let e = self.price.0;
-        let f = self.price.1;
-        // [[a,b]][x]=[e]
-        // [[c,d]][y]=[f]
-        let determinant = a * d - b * c;
-        // Check if the determinant is zero (matrix is singular, no unique solution)
-        // dbg!(determinant);
-        if determinant == 0 {
-            return None;
-        }
-
-        // Multiply the inverse matrix by the result matrix [e, f]
-        let x = d * e - b * f;
-        let y = -c * e + a * f;
-        // dbg!(x, y);
-        if gcd(x, determinant) == determinant.abs() && gcd(y, determinant) == determinant.abs() {
-            Some((x / determinant, y / determinant))
-        } else {
-            None
-        }
+        let [a_press, b_press] = solve_2x2(
+            [
+                [self.button_a.0, self.button_b.0],
+                [self.button_a.1, self.button_b.1],
+            ],
+            [self.price.0, self.price.1],
+        )?;
+        Some((a_press, b_press))
     }
 }
 
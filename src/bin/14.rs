@@ -1,73 +1,41 @@
 advent_of_code::solution!(14);
 
-use std::collections::HashSet;
-
-use itertools::Itertools;
-use nalgebra::{vector, Vector2, VectorView2};
+use advent_of_code::bitset::BitGrid;
+use advent_of_code::cycle::{first_in_period, NoneInPeriod};
+use advent_of_code::parse::ints;
+use advent_of_code::render::{Picture, Pixel, Rgb};
 use num::ToPrimitive;
 
+/// An `(x, y)` pair.
+type Vector = (i64, i64);
+
 #[derive(Debug)]
-struct Robot {
-    pos: Vector2<i64>,
-    vel: Vector2<i64>,
+pub struct Robot {
+    pos: Vector,
+    vel: Vector,
 }
 
 impl Robot {
-    fn new(pos: Vector2<i64>, vel: Vector2<i64>) -> Self {
+    fn new(pos: Vector, vel: Vector) -> Self {
         Self { pos, vel }
     }
 
     fn from_input(input: &str) -> Option<Self> {
-        // dbg!(input);
-        let mut iter = input.split_whitespace();
-        let pos_input = iter.next()?;
-        // dbg!(pos_input);
-        assert!(pos_input.starts_with("p="));
-        let pos: [i64; 2] = pos_input[2..]
-            .split(',')
-            .filter_map(|val| val.parse::<i64>().ok())
-            .collect_vec()
-            .try_into()
-            .ok()?;
-        // dbg!(pos);
-
-        let vel_input = iter.next()?;
-        assert!(vel_input.starts_with("v="));
-        let vel: [i64; 2] = vel_input[2..]
-            .split(',')
-            .filter_map(|val| val.parse::<i64>().ok())
-            .collect_vec()
-            .try_into()
-            .ok()?;
-        // dbg!(vel);
-
-        Some(Robot::new(Vector2::from(pos), Vector2::from(vel)))
+        let [px, py, vx, vy] = ints(input)?;
+        Some(Robot::new((px, py), (vx, vy)))
     }
 
-    fn predict(&self, time: i64, space: VectorView2<i64>) -> Vector2<i64> {
-        let tmp = self.pos + (time * self.vel);
-        let tmp: [i64; 2] = tmp
-            .into_iter()
-            .zip(space)
-            .map(|(a, b)| ((a % b) + b) % b)
-            .collect_vec()
-            .try_into()
-            .unwrap();
-        tmp.into()
-    }
-
-    fn advance(&mut self, space: VectorView2<i64>) {
-        self.pos += self.vel;
-        self.pos.iter_mut().zip(space).for_each(|(a, b)| {
-            *a %= b;
-            *a += b;
-            *a %= b;
-        });
+    /// Returns the position after `time` seconds, wrapping around the edges of `space`.
+    fn predict(&self, time: i64, space: Vector) -> Vector {
+        (
+            (self.pos.0 + time * self.vel.0).rem_euclid(space.0),
+            (self.pos.1 + time * self.vel.1).rem_euclid(space.1),
+        )
     }
 }
 
-fn parse_input(input: &str) -> Option<Vec<Robot>> {
-    input.lines().map(|line| Robot::from_input(line)).collect()
+pub fn parse_input(input: &str) -> Option<Vec<Robot>> {
+    input.lines().map(Robot::from_input).collect()
 }
 
 pub fn part_one(input: &str) -> Option<u64> {
@@ -77,21 +45,19 @@ pub fn part_one(input: &str) -> Option<u64> {
     // dbg!(space);
     let mut count = [0u64; 4];
     for robot in robots {
-        let pos = robot.predict(100, space.as_view());
+        let pos = robot.predict(100, space);
         // dbg!(pos);
-        let x = pos.x;
-        let y = pos.y;
-        match (x, y) {
-            (i, j) if i > space.x / 2 && j > space.y / 2 => {
+        match pos {
+            (i, j) if i > space.0 / 2 && j > space.1 / 2 => {
                 count[0] += 1;
             }
-            (i, j) if i < space.x / 2 && j > space.y / 2 => {
+            (i, j) if i < space.0 / 2 && j > space.1 / 2 => {
                 count[1] += 1;
             }
-            (i, j) if i < space.x / 2 && j < space.y / 2 => {
+            (i, j) if i < space.0 / 2 && j < space.1 / 2 => {
                 count[2] += 1;
             }
-            (i, j) if i > space.x / 2 && j < space.y / 2 => {
+            (i, j) if i > space.0 / 2 && j < space.1 / 2 => {
                 count[3] += 1;
             }
             (_, _) => {}
@@ -101,42 +67,56 @@ pub fn part_one(input: &str) -> Option<u64> {
     Some(count.into_iter().product::<u64>())
 }
 
+/// Returns the first second at which no two robots stand on the same tile, which is when they
+/// draw the tree. Fails if the robots overlap at every second until they are back at the start.
+pub fn find_tree(robots: &[Robot]) -> Result<usize, NoneInPeriod> {
+    let space = get_space();
+    // every robot is back at its start after `space.0 * space.1` seconds
+    let period = (space.0 * space.1) as usize;
+    let mut visited = BitGrid::new(space.0 as usize, space.1 as usize);
+    let no_overlap = |&time: &usize| {
+        visited.clear();
+        robots.iter().all(|robot| {
+            let (x, y) = robot.predict(time as i64, space);
+            visited.insert((y as usize, x as usize))
+        })
+    };
+    first_in_period(1, period, no_overlap)
+}
+
 pub fn part_two(input: &str) -> Option<u64> {
     // If it display a picture, none of them should stack on each other?
-    let mut robots = parse_input(input).unwrap();
-    let space = get_space();
-    let mut count = 0usize;
-    loop {
-        robots
-            .iter_mut()
-            .for_each(|robot| robot.advance(space.as_view()));
-        count += 1;
-
-        let mut visited = HashSet::new();
-        if robots.iter().all(|robot| visited.insert(robot.pos)) {
-            break;
-        }
+    let robots = parse_input(input).unwrap();
+    let time = find_tree(&robots).map_err(|err| eprintln!("{err}")).ok()?;
+    if let Some(path) = std::env::var_os("AOC_RENDER") {
+        render(&robots, time as i64)
+            .save(path, 4)
+            .expect("cannot save the picture of the tree");
     }
-    // print the grid
-
-    // let mut grid = vec![vec!['.'; space.y as usize]; space.x as usize];
-    // for robot in robots {
-    //     grid[robot.pos.x as usize][robot.pos.y as usize] = '#';
-    // }
-    // for row in grid {
-    //     for cell in row {
-    //         print!("{}", cell);
-    //     }
-    //     println!("");
-    // }
-    count.to_u64()
+    time.to_u64()
+}
+
+/// Shows the robots after `time` seconds. `part_two` saves the tree to the path in `AOC_RENDER`,
+/// e.g. `AOC_RENDER=target/day14.png cargo solve 14`.
+fn render(robots: &[Robot], time: i64) -> Picture {
+    let space = get_space();
+    let robots = robots.iter().map(|robot| {
+        let (x, y) = robot.predict(time, space);
+        (y as usize, x as usize)
+    });
+    Picture::from_points(
+        space.0 as usize,
+        space.1 as usize,
+        robots,
+        Pixel::colored('#', Rgb::GREEN),
+    )
 }
 
-fn get_space() -> Vector2<i64> {
+fn get_space() -> Vector {
     if cfg!(test) {
-        vector![11, 7]
+        (11, 7)
     } else {
-        vector![101, 103]
+        (101, 103)
     }
 }
 
@@ -155,4 +135,17 @@ mod tests {
         let result = part_two(&advent_of_code::template::read_file("examples", DAY));
         assert_eq!(result, Some(1));
     }
+
+    #[test]
+    fn test_find_tree_without_tree() {
+        // two robots that move together overlap at every second
+        let robots = parse_input("p=0,4 v=3,-3\np=0,4 v=3,-3").unwrap();
+        assert_eq!(
+            find_tree(&robots),
+            Err(NoneInPeriod {
+                start: 1,
+                period: 77
+            })
+        );
+    }
 }
@@ -3,45 +3,14 @@ use std::{
     iter,
 };
 
-use itertools::Itertools;
+use advent_of_code::parse::{grid_of, lines_of, sections, ParseError};
+use advent_of_code::point::{Dir4, Point};
+use advent_of_code::render::{Picture, Pixel, Rgb};
+use itertools::{iproduct, Itertools};
 use num::ToPrimitive;
 
 advent_of_code::solution!(15);
 
-#[derive(Debug, Clone)]
-enum Dir {
-    Up,
-    Down,
-    Left,
-    Right,
-}
-
-impl Dir {
-    fn step(&self, (x, y): (usize, usize)) -> (usize, usize) {
-        match self {
-            Dir::Up => (x - 1, y),
-            Dir::Down => (x + 1, y),
-            Dir::Left => (x, y - 1),
-            Dir::Right => (x, y + 1),
-        }
-    }
-}
-
-impl From<char> for Dir {
-    fn from(value: char) -> Self {
-        match value {
-            '^' => Self::Up,
-            'v' => Self::Down,
-            '<' => Self::Left,
-            '>' => Self::Right,
-            c => {
-                dbg!(c);
-                unreachable!()
-            }
-        }
-    }
-}
-
 #[derive(Debug, Clone, Eq, PartialEq)]
 enum Cell {
     Empty,
@@ -63,63 +32,44 @@ impl Helper {
         Self { robot, map }
     }
 
-    fn parse_grid(input: &str) -> Self {
-        let m = input.lines().count();
-        let n = input.lines().next().unwrap().len();
-        // dbg!(m, n);
-        let mut map = vec![vec![Cell::Empty; n]; m];
-        let mut robot = None;
-        for (i, (j, cell)) in input
-            .lines()
-            .enumerate()
-            .flat_map(|(i, row)| iter::repeat(i).zip(row.trim().chars().enumerate()))
-        {
-            map[i][j] = match cell {
-                '#' => Cell::Wall,
-                'O' => Cell::Box,
-                '.' => Cell::Empty,
-                '@' => {
-                    let _ = robot.insert((i, j));
-                    // Cell::Empty
-                    Cell::Robot
-                }
-                _ => unreachable!(),
-            };
-        }
-        Self::new(robot.unwrap(), map)
+    fn from_map(input: &str, map: Vec<Vec<Cell>>) -> Result<Self, ParseError> {
+        let robot = iproduct!(0..map.len(), 0..map[0].len())
+            .find(|&(i, j)| map[i][j] == Cell::Robot)
+            .ok_or_else(|| ParseError::end_of(input, "a robot `@`"))?;
+        Ok(Self::new(robot, map))
     }
-    fn parse_wide_grid(input: &str) -> Self {
-        let m = input.lines().count();
-        let n = input.lines().next().unwrap().len() * 2;
-        // dbg!(m, n);
-        let mut map = vec![vec![Cell::Empty; n]; m];
-        let mut robot = None;
-        for (i, (j, cell)) in input
-            .lines()
-            .enumerate()
-            .flat_map(|(i, row)| iter::repeat(i).zip(row.trim().chars().enumerate()))
-        {
-            match cell {
-                '#' => {
-                    map[i][2 * j] = Cell::Wall;
-                    map[i][2 * j + 1] = Cell::Wall;
-                }
-                'O' => {
-                    map[i][2 * j] = Cell::BoxLeft;
-                    map[i][2 * j + 1] = Cell::BoxRight;
-                }
-                '.' => {
-                    map[i][2 * j] = Cell::Empty;
-                    map[i][2 * j + 1] = Cell::Empty;
-                }
-                '@' => {
-                    let _ = robot.insert((i, 2 * j));
-                    map[i][2 * j] = Cell::Robot;
-                }
-                _ => unreachable!(),
-            };
-        }
-        Self::new(robot.unwrap(), map)
+
+    fn parse_grid(input: &str) -> Result<Self, ParseError> {
+        let map = grid_of(input, "one of `#O.@`", |cell| match cell {
+            '#' => Some(Cell::Wall),
+            'O' => Some(Cell::Box),
+            '.' => Some(Cell::Empty),
+            '@' => Some(Cell::Robot),
+            _ => None,
+        })?;
+        Self::from_map(input, map)
+    }
+
+    fn parse_wide_grid(input: &str) -> Result<Self, ParseError> {
+        let map = grid_of(input, "one of `#O.@`", |cell| match cell {
+            '#' => Some([Cell::Wall, Cell::Wall]),
+            'O' => Some([Cell::BoxLeft, Cell::BoxRight]),
+            '.' => Some([Cell::Empty, Cell::Empty]),
+            '@' => Some([Cell::Robot, Cell::Empty]),
+            _ => None,
+        })?;
+        let map = map
+            .into_iter()
+            .map(|row| row.into_iter().flatten().collect_vec())
+            .collect_vec();
+        Self::from_map(input, map)
+    }
+
+    fn step(&self, pos: (usize, usize), dir: Dir4) -> (usize, usize) {
+        Point::from(pos)
+            .step(dir)
+            .to_pos(self.map[0].len(), self.map.len())
+            .expect("the map is enclosed by walls")
     }
 
     // In wide case, always return BoxLeft position
@@ -131,7 +81,7 @@ impl Helper {
         }
     }
 
-    fn get_movable(&self, current: (usize, usize), dir: &Dir) -> Option<Vec<(usize, usize)>> {
+    fn get_movable(&self, current: (usize, usize), dir: Dir4) -> Option<Vec<(usize, usize)>> {
         assert_eq!(self.map[current.0][current.1], Cell::Robot);
         let mut movable = vec![];
         let mut queue = VecDeque::new();
@@ -143,26 +93,26 @@ impl Helper {
                 Cell::Empty => {}
                 Cell::Wall => return None,
                 Cell::Box | Cell::Robot => {
-                    let next = self.normalize(dir.step(pos));
+                    let next = self.normalize(self.step(pos, dir));
                     queue.push_back(next);
                     movable.push(pos)
                 }
                 Cell::BoxLeft => {
                     match dir {
-                        Dir::Up | Dir::Down => {
-                            for item in vec![pos, (pos.0, pos.1 + 1)] {
-                                let next = self.normalize(dir.step(item));
+                        Dir4::North | Dir4::South => {
+                            for item in [pos, (pos.0, pos.1 + 1)] {
+                                let next = self.normalize(self.step(item, dir));
                                 if visited.insert(next) {
                                     queue.push_back(next);
                                 }
                             }
                         }
-                        Dir::Left => {
-                            let next = self.normalize(dir.step(pos));
+                        Dir4::West => {
+                            let next = self.normalize(self.step(pos, dir));
                             queue.push_back(next);
                         }
-                        Dir::Right => {
-                            let next = self.normalize(dir.step(dir.step(pos)));
+                        Dir4::East => {
+                            let next = self.normalize(self.step(self.step(pos, dir), dir));
                             queue.push_back(next);
                         }
                     }
@@ -174,54 +124,69 @@ impl Helper {
         Some(movable)
     }
 
-    fn try_move(&mut self, dir: Dir) {
-        if let Some(movable) = self.get_movable(self.robot, &dir) {
+    /// Draws the map the way the puzzle does.
+    fn picture(&self) -> Picture {
+        Picture::from_rows(&self.map, |cell| match cell {
+            Cell::Empty => Pixel::EMPTY,
+            Cell::Wall => Pixel::plain('#'),
+            Cell::Box => Pixel::colored('O', Rgb::YELLOW),
+            Cell::BoxLeft => Pixel::colored('[', Rgb::YELLOW),
+            Cell::BoxRight => Pixel::colored(']', Rgb::YELLOW),
+            Cell::Robot => Pixel::colored('@', Rgb::GREEN),
+        })
+    }
+
+    fn try_move(&mut self, dir: Dir4) {
+        if let Some(movable) = self.get_movable(self.robot, dir) {
             // dbg!(&movable, &dir);
             for pos in movable.into_iter().rev() {
                 match self.map[pos.0][pos.1] {
                     Cell::Empty | Cell::Wall | Cell::BoxRight => {
-                        dbg!(pos);
-                        dbg!(&self.map[pos.0][pos.1]);
-                        unreachable!()
+                        let mut picture = self.picture();
+                        picture.set(pos, Pixel::colored('X', Rgb::RED));
+                        panic!(
+                            "cannot move {:?} at {pos:?} (marked X):\n{picture}",
+                            self.map[pos.0][pos.1]
+                        )
                     }
                     Cell::Box | Cell::Robot => {
-                        let next_pos = dir.step(pos);
+                        let next_pos = self.step(pos, dir);
                         self.map[next_pos.0][next_pos.1] = self.map[pos.0][pos.1].clone();
                         self.map[pos.0][pos.1] = Cell::Empty;
                     }
                     Cell::BoxLeft => {
                         let left = pos;
                         let right = (pos.0, pos.1 + 1);
-                        let next_left = dir.step(left);
-                        let next_right = dir.step(right);
+                        let next_left = self.step(left, dir);
+                        let next_right = self.step(right, dir);
                         self.map[next_left.0][next_left.1] = Cell::BoxLeft;
                         self.map[next_right.0][next_right.1] = Cell::BoxRight;
                         match dir {
-                            Dir::Up | Dir::Down => {
+                            Dir4::North | Dir4::South => {
                                 self.map[left.0][left.1] = Cell::Empty;
                                 self.map[right.0][right.1] = Cell::Empty;
                             }
-                            Dir::Left => {
+                            Dir4::West => {
                                 self.map[right.0][right.1] = Cell::Empty;
                             }
-                            Dir::Right => {
+                            Dir4::East => {
                                 self.map[left.0][left.1] = Cell::Empty;
                             }
                         }
                     }
                 };
             }
-            self.robot = dir.step(self.robot);
+            self.robot = self.step(self.robot, dir);
         }
     }
 }
 
 pub fn part_one(input: &str) -> Option<u64> {
     // dbg!(input);
-    let (grid_input, moves_input) = input.split("\n\n").collect_tuple()?;
-    let moves = parse_moves(moves_input);
+    let [grid_input, moves_input]: [_; 2] = sections(input).try_into().ok()?;
+    let moves = moves_input.parse(parse_moves).unwrap();
     // dbg!(&moves);
-    let mut helper = Helper::parse_grid(grid_input);
+    let mut helper = grid_input.parse(Helper::parse_grid).unwrap();
     for dir in moves {
         helper.try_move(dir);
     }
@@ -246,21 +211,23 @@ pub fn part_one(input: &str) -> Option<u64> {
     sum.to_u64()
 }
 
-fn parse_moves(moves_input: &str) -> Vec<Dir> {
-    let moves = moves_input
-        .chars()
-        .filter(|c| c != &'\n')
-        .map(Dir::from)
-        .collect_vec();
-    moves
+fn parse_moves(moves_input: &str) -> Result<Vec<Dir4>, ParseError> {
+    let moves = lines_of(moves_input, |line| {
+        line.char_indices()
+            .map(|(j, c)| {
+                Dir4::from_char(c).ok_or_else(|| ParseError::at(line, j, "one of `^v<>`"))
+            })
+            .collect::<Result<Vec<_>, _>>()
+    })?;
+    Ok(moves.into_iter().flatten().collect_vec())
 }
 
 pub fn part_two(input: &str) -> Option<u64> {
     // dbg!(input);
-    let (grid_input, moves_input) = input.split("\n\n").collect_tuple()?;
-    let moves = parse_moves(moves_input);
+    let [grid_input, moves_input]: [_; 2] = sections(input).try_into().ok()?;
+    let moves = moves_input.parse(parse_moves).unwrap();
     // dbg!(&moves);
-    let mut helper = Helper::parse_wide_grid(grid_input);
+    let mut helper = grid_input.parse(Helper::parse_wide_grid).unwrap();
     for dir in moves {
         helper.try_move(dir);
         // dbg!(&helper);
@@ -1,213 +1,80 @@
+use advent_of_code::bitset::BitGrid;
+use advent_of_code::parse::{grid_of, ParseError};
+use advent_of_code::point::{Dir4, Point};
+use advent_of_code::search::{dijkstra, dijkstra_paths};
 use itertools::iproduct;
-use num::{traits::WrappingSub, ToPrimitive};
-use std::{
-    cmp::Reverse,
-    collections::{BinaryHeap, HashSet},
-};
+use num::ToPrimitive;
 
 advent_of_code::solution!(16);
 
 #[derive(Debug, PartialEq, Eq)]
-enum Cell {
+pub enum Cell {
     Empty,
     Wall,
-    Visited(usize),
 }
 
-#[derive(Debug, PartialEq, Eq, PartialOrd, Ord, Clone)]
-enum Dir {
-    North,
-    East,
-    South,
-    West,
+/// A reindeer's position and the direction it faces.
+type State = ((usize, usize), Dir4);
+
+/// Moves that are not straight ahead pay for a rotation.
+fn moves(grid: &[Vec<Cell>], (pos, facing): State) -> impl Iterator<Item = (State, usize)> + '_ {
+    Dir4::ALL.into_iter().filter_map(move |dir| {
+        let next = Point::from(pos)
+            .step(dir)
+            .to_pos(grid[0].len(), grid.len())?;
+        let cost = if dir == facing { 1 } else { 1001 };
+        (grid[next.0][next.1] == Cell::Empty).then_some(((next, dir), cost))
+    })
 }
 
-const DIRECTIONS: [Dir; 4] = [Dir::North, Dir::East, Dir::South, Dir::West];
-
-impl Dir {
-    fn move_from(&self, pos: &(usize, usize)) -> (usize, usize) {
-        let mut pos = pos.clone();
-        match self {
-            Dir::North => pos.0 -= 1,
-            Dir::East => pos.1 += 1,
-            Dir::South => pos.0 += 1,
-            Dir::West => pos.1 -= 1,
-        };
-        pos
-    }
-}
-
-#[derive(Debug, PartialEq, Eq, Clone)]
-struct Reindeer {
-    pos: Vec<(usize, usize)>,
-    facing: Dir,
-    rotate: usize,
-}
-
-impl PartialOrd for Reindeer {
-    fn partial_cmp(&self, other: &Self) -> Option<std::cmp::Ordering> {
-        Some(self.score().cmp(&other.score()))
-    }
-}
-
-impl Ord for Reindeer {
-    fn cmp(&self, other: &Self) -> std::cmp::Ordering {
-        (self.score()).cmp(&other.score())
-    }
-}
-
-impl Reindeer {
-    fn new(pos: (usize, usize), facing: Dir, rotate: usize) -> Self {
-        Self {
-            pos: vec![pos],
-            facing,
-            rotate,
-        }
-    }
-
-    fn init(pos: (usize, usize)) -> Self {
-        Self::new(pos, Dir::East, 0)
-    }
-
-    fn move_next(self) -> [Self; 4] {
-        let mut next = std::array::from_fn(|_| self.clone());
-        for (item, dir) in next.iter_mut().zip(DIRECTIONS) {
-            item.pos.push(dir.move_from(item.position()));
-            item.rotate += if dir == item.facing { 0 } else { 1 };
-            item.facing = dir;
-        }
-        next
-    }
-
-    #[inline]
-    fn score(&self) -> usize {
-        self.rotate * 1000 + (self.pos.len() - 1)
-    }
-
-    #[inline]
-    fn position(&self) -> &(usize, usize) {
-        self.pos.last().unwrap()
-    }
-}
-
-fn parse_input(input: &str) -> (Vec<Vec<Cell>>, (usize, usize), (usize, usize)) {
-    let m = input.lines().count();
-    let n = input.lines().next().unwrap().len();
-    let mut grid = vec![];
-    grid.reserve(m);
-    let mut start = None;
-    let mut end = None;
-    for (i, line) in input.lines().enumerate() {
-        let mut row = vec![];
-        row.reserve(n);
-        for (j, c) in line.chars().enumerate() {
-            match c {
-                '#' => row.push(Cell::Wall),
-                '.' => row.push(Cell::Empty),
-                'S' => {
-                    let _ = start.insert((i, j));
-                    row.push(Cell::Empty)
-                }
-                'E' => {
-                    let _ = end.insert((i, j));
-                    row.push(Cell::Empty)
-                }
-                _ => unreachable!(),
-            }
-        }
-        grid.push(row);
-    }
-    (grid, start.unwrap(), end.unwrap())
+pub type Maze = (Vec<Vec<Cell>>, (usize, usize), (usize, usize));
+
+pub fn parse_input(input: &str) -> Result<Maze, ParseError> {
+    let chars = grid_of(input, "one of `#.SE`", |c| "#.SE".contains(c).then_some(c))?;
+    let find = |target: char| {
+        iproduct!(0..chars.len(), 0..chars[0].len())
+            .find(|&(i, j)| chars[i][j] == target)
+            .ok_or_else(|| ParseError::end_of(input, format!("a tile `{target}`")))
+    };
+    let start = find('S')?;
+    let end = find('E')?;
+    let grid = chars
+        .iter()
+        .map(|row| {
+            row.iter()
+                .map(|&c| if c == '#' { Cell::Wall } else { Cell::Empty })
+                .collect()
+        })
+        .collect();
+    Ok((grid, start, end))
 }
 
 pub fn part_one(input: &str) -> Option<u64> {
-    // dbg!(input);
-    let (mut grid, start, end) = parse_input(input);
-    // dbg!(start, end);
-    let mut priority_queue = BinaryHeap::new();
-    priority_queue.push(Reverse(Reindeer::init(start)));
-    grid[start.0][start.1] = Cell::Visited(0);
-
-    while let Some(Reverse(reindeer)) = priority_queue.pop() {
-        let current_pos = reindeer.position();
-        if current_pos == &end {
-            return reindeer.score().to_u64();
-        }
-        match grid[current_pos.0][current_pos.1] {
-            Cell::Visited(score) if score != reindeer.score() => continue,
-            Cell::Visited(_) => {}
-            _ => unreachable!(),
-        }
-        for next in reindeer.move_next() {
-            let next_pos = next.position();
-            match grid[next_pos.0][next_pos.1] {
-                Cell::Empty => {
-                    grid[next_pos.0][next_pos.1] = Cell::Visited(next.score());
-                    priority_queue.push(Reverse(next));
-                }
-                Cell::Visited(ref mut score) if *score > next.score() => {
-                    *score = next.score();
-                    priority_queue.push(Reverse(next));
-                }
-                Cell::Wall | Cell::Visited(_) => {}
-            }
-        }
-    }
-    unreachable!()
+    let (grid, start, end) = parse_input(input).unwrap();
+    let (_, score) = dijkstra(
+        (start, Dir4::East),
+        |state| moves(&grid, *state),
+        |&(pos, _)| pos == end,
+    )?;
+    score.to_u64()
 }
 
-// 475 too low
 pub fn part_two(input: &str) -> Option<u64> {
-    let (mut grid, start, end) = parse_input(input);
-    let mut priority_queue = BinaryHeap::new();
-    priority_queue.push(Reverse(Reindeer::init(start)));
-    grid[start.0][start.1] = Cell::Visited(0);
-    let mut min_score = None;
-    let mut nice_tile = HashSet::new();
-
-    while let Some(Reverse(reindeer)) = priority_queue.pop() {
-        let current_pos = reindeer.position();
-        if min_score.is_some() && reindeer.score() > min_score.unwrap() {
-            return nice_tile.len().to_u64();
-        }
-        if current_pos == &end {
-            let _ = min_score.insert(reindeer.score());
-            reindeer.pos.into_iter().for_each(|val| {
-                nice_tile.insert(val);
-            });
-            continue;
-        }
-        for next in reindeer.move_next() {
-            let next_pos = next.position();
-            match grid[next_pos.0][next_pos.1] {
-                Cell::Empty => {
-                    grid[next_pos.0][next_pos.1] = Cell::Visited(next.pos.len());
-                    priority_queue.push(Reverse(next));
-                }
-                Cell::Visited(ref mut step) if *step >= next.pos.len() => {
-                    *step = next.pos.len();
-                    priority_queue.push(Reverse(next));
-                }
-                Cell::Wall | Cell::Visited(_) => {}
-            }
-        }
-    }
-    nice_tile.len().to_u64()
-}
-
-fn debug(grid: &[Vec<Cell>]) {
-    for row in grid {
-        for cell in row {
-            let s = match cell {
-                Cell::Empty => ".".to_owned(),
-                Cell::Wall => "#".to_owned(),
-                Cell::Visited(score) => score.to_string(),
-            };
-            print!("{:7}", s);
-        }
-        println!("");
+    let (grid, start, end) = parse_input(input).unwrap();
+    let paths = dijkstra_paths((start, Dir4::East), |state| moves(&grid, *state));
+
+    let arrivals = Dir4::ALL.map(|dir| paths.distance(&(end, dir)));
+    let min_score = arrivals.iter().flatten().min()?;
+    let ends = Dir4::ALL
+        .into_iter()
+        .zip(arrivals)
+        .filter(|(_, score)| score.as_ref() == Some(min_score))
+        .map(|(dir, _)| (end, dir));
+    let mut nice_tiles = BitGrid::new(grid[0].len(), grid.len());
+    for (pos, _) in paths.on_shortest_paths(ends) {
+        nice_tiles.insert(pos);
     }
-    println!("");
+    nice_tiles.len().to_u64()
 }
 
 #[cfg(test)]
@@ -4,7 +4,7 @@ use num::ToPrimitive;
 advent_of_code::solution!(17);
 
 #[derive(Debug, PartialEq, Eq)]
-enum Opcode {
+pub enum Opcode {
     Adv,
     Bxl,
     Bst,
@@ -32,15 +32,15 @@ impl From<usize> for Opcode {
 }
 
 #[derive(Debug, Clone)]
-struct Computer {
-    registers: [usize; 3],
-    program: Vec<usize>,
+pub struct Computer {
+    pub registers: [usize; 3],
+    pub program: Vec<usize>,
     instruction_pointer: usize,
     output: Vec<usize>,
 }
 
 impl Computer {
-    fn new(registers: [usize; 3], program: Vec<usize>) -> Self {
+    pub fn new(registers: [usize; 3], program: Vec<usize>) -> Self {
         Self {
             registers,
             program,
@@ -62,16 +62,16 @@ impl Computer {
         numerator >> self.get_combo_operand(operand)
     }
 
-    fn run_until_halts(&mut self) -> Vec<usize> {
+    pub fn run_until_halts(&mut self) -> Vec<usize> {
         while self.run_once() {}
         self.output.clone()
     }
 
-    fn run_once(&mut self) -> bool {
+    pub fn run_once(&mut self) -> bool {
         if self.instruction_pointer >= self.program.len() {
             return false;
         }
-        let opcode: Opcode = self.program[self.instruction_pointer].try_into().unwrap();
+        let opcode: Opcode = self.program[self.instruction_pointer].into();
         let operand = self.program[self.instruction_pointer + 1];
         match opcode {
             Opcode::Adv => self.registers[0] = self.do_division(operand),
@@ -92,13 +92,13 @@ impl Computer {
         true
     }
 
-    fn parse_from(input: &str) -> Option<Self> {
+    pub fn parse_from(input: &str) -> Option<Self> {
         let mut iter = input.lines();
         let mut register = [0; 3];
-        for i in 0..3 {
+        for value in register.iter_mut() {
             let line = iter.next().unwrap().trim();
             let pattern = line.find(": ").unwrap();
-            register[i] = line[pattern + 2..].parse::<usize>().ok()?;
+            *value = line[pattern + 2..].parse::<usize>().ok()?;
         }
         iter.next();
         let line = iter.next()?.trim();
@@ -126,8 +126,8 @@ pub fn test_factor() -> usize {
 }
 
 fn dfs(value: usize, targets: &[usize], computer: &Computer) -> Option<usize> {
-    if targets.len() == 0 {
-        return Some(value >> 3 - test_factor());
+    if targets.is_empty() {
+        return Some(value >> (3 - test_factor()));
     }
     for guess in 0..8 {
         let mut clone = computer.clone();
@@ -1,8 +1,8 @@
-use std::{
-    cmp::Reverse,
-    collections::{BinaryHeap, HashSet},
-};
+use std::collections::HashSet;
 
+use advent_of_code::point::{Dir4, Point};
+use advent_of_code::search::bfs;
+use advent_of_code::union_find::first_blocking;
 use itertools::Itertools;
 use num::ToPrimitive;
 
@@ -32,82 +32,44 @@ fn parse_line(line: &str) -> Option<(usize, usize)> {
         .collect_vec()
         .try_into()
         .ok()?;
-    return Some(values.into());
+    Some(values.into())
 }
 
-fn parse_input(input: &str) -> Option<Vec<(usize, usize)>> {
+pub fn parse_input(input: &str) -> Option<Vec<(usize, usize)>> {
     input.lines().map(parse_line).collect()
 }
 
-fn get_successors(pos: &(usize, usize)) -> [(usize, usize); 4] {
-    [
-        (pos.0 + 1, pos.1),
-        (pos.0.wrapping_sub(1), pos.1),
-        (pos.0, pos.1 + 1),
-        (pos.0, pos.1.wrapping_sub(1)),
-    ]
-}
-
 // 314 to high
 pub fn part_one(input: &str) -> Option<u64> {
     let positions = parse_input(input)?;
-    dbg!(positions.len());
+    // dbg!(positions.len());
     let grid_size = get_grid_size();
     path_finding(grid_size, &positions[..get_take_amount()])
 }
 
 fn path_finding(grid_size: (usize, usize), corrupted: &[(usize, usize)]) -> Option<u64> {
-    let corrupted = corrupted.into_iter().collect::<HashSet<_>>();
-
-    let start = (0, 0);
+    let corrupted = corrupted.iter().collect::<HashSet<_>>();
     let end = (grid_size.0 - 1, grid_size.1 - 1);
-    let total_step = 0;
-    let mut prioity_queue = BinaryHeap::new();
-    prioity_queue.push((Reverse(total_step), vec![start]));
-    let mut visited = vec![vec![usize::MAX; grid_size.1]; grid_size.0];
-    visited[0][0] = total_step;
 
-    while let Some((Reverse(total_step), path)) = prioity_queue.pop() {
-        let current = path.last().unwrap();
-        // dbg!(current);
-        if current == &end {
-            return (path.len() - 1).to_u64();
-        }
-        if visited[current.0][current.1] != total_step {
-            continue;
-        }
-        for next in get_successors(current) {
-            if next.0 >= grid_size.0 || next.1 >= grid_size.1 || corrupted.contains(&next) {
-                continue;
-            }
-            let next_total_step = total_step + 1;
-            if next_total_step < visited[next.0][next.1] {
-                visited[next.0][next.1] = next_total_step;
-                let mut next_path = path.clone();
-                next_path.push(next);
-                prioity_queue.push((Reverse(next_total_step), next_path));
-            }
-        }
-    }
-    None
+    let successors = |pos: &(usize, usize)| {
+        Dir4::ALL
+            .into_iter()
+            .filter_map(|dir| Point::from(*pos).step(dir).to_pos(grid_size.1, grid_size.0))
+            .filter(|next| !corrupted.contains(next))
+            .collect_vec()
+    };
+    let path = bfs((0, 0), successors, |pos| *pos == end)?;
+    (path.len() - 1).to_u64()
 }
 
 pub fn part_two(input: &str) -> Option<String> {
     let positions = parse_input(input)?;
-    dbg!(positions.len());
     let grid_size = get_grid_size();
 
-    let mut left = 0;
-    let mut right = positions.len();
-    while left < right {
-        let mid = (left + right) / 2;
-        if path_finding(grid_size, &positions[..mid]).is_some() {
-            left = mid + 1;
-        } else {
-            right = mid;
-        }
-    }
-    Some(positions[left - 1].0.to_string() + "," + &positions[left - 1].1.to_string())
+    let end = (grid_size.0 - 1, grid_size.1 - 1);
+    let first = first_blocking(grid_size.0, grid_size.1, &positions, (0, 0), end)?;
+    let (x, y) = positions[first];
+    Some(format!("{x},{y}"))
 }
 
 #[cfg(test)]
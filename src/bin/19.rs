@@ -1,11 +1,10 @@
-use std::collections::HashMap;
-
+use advent_of_code::memo::Memo;
 use itertools::Itertools;
 use num::ToPrimitive;
 
 advent_of_code::solution!(19);
 
-fn parse_input(input: &str) -> (Vec<&str>, Vec<&str>) {
+pub fn parse_input(input: &str) -> (Vec<&str>, Vec<&str>) {
     let mut iter = input.lines();
     let building_blocks = iter.next().unwrap().split(", ").collect_vec();
     let _ = iter.next();
@@ -13,33 +12,26 @@ fn parse_input(input: &str) -> (Vec<&str>, Vec<&str>) {
     (building_blocks, targets)
 }
 
-// Ugly approach, but it works
-fn try_build_target<'a>(
-    building_blocks: &[&'a str],
-    target: &'a str,
-    dp: &mut HashMap<&'a str, u64>,
-) -> u64 {
-    if dp.contains_key(target) {
-        return *dp.get(target).unwrap();
-    }
-    let mut count = 0;
-    for block in building_blocks {
-        if target.starts_with(block) {
-            count += try_build_target(building_blocks, &target[block.len()..], dp)
-        }
-    }
-    dp.insert(target, count);
-    return count;
+/// Counts the ways to build a target from the building blocks.
+fn arrangements<'a>(building_blocks: &'a [&'a str]) -> Memo<'a, &'a str, u64> {
+    let mut memo = Memo::new(move |memo, target: &&'a str| {
+        building_blocks
+            .iter()
+            .filter_map(|block| target.strip_prefix(block))
+            .map(|rest| memo.get(rest))
+            .sum()
+    });
+    memo.insert("", 1);
+    memo
 }
 
 pub fn part_one(input: &str) -> Option<u64> {
     let (building_blocks, targets) = parse_input(input);
     // dbg!(&building_blocks, &targets);
-    let mut dp = HashMap::new();
-    dp.insert("", 1u64);
+    let mut arrangements = arrangements(&building_blocks);
     targets
         .into_iter()
-        .map(|target| try_build_target(&building_blocks, target, &mut dp))
+        .map(|target| arrangements.get(target))
         .filter(|val| val != &0)
         .count()
         .to_u64()
@@ -48,11 +40,10 @@ pub fn part_one(input: &str) -> Option<u64> {
 pub fn part_two(input: &str) -> Option<u64> {
     let (building_blocks, targets) = parse_input(input);
     // dbg!(&building_blocks, &targets);
-    let mut dp = HashMap::new();
-    dp.insert("", 1u64);
+    let mut arrangements = arrangements(&building_blocks);
     targets
         .into_iter()
-        .map(|target| try_build_target(&building_blocks, target, &mut dp))
+        .map(|target| arrangements.get(target))
         .sum::<u64>()
         .to_u64()
 }
@@ -1,6 +1,5 @@
 use std::collections::HashMap;
 
-use itertools::Itertools;
 use num::ToPrimitive;
 
 advent_of_code::solution!(20);
@@ -31,7 +30,7 @@ fn get_successor(pos: &(usize, usize), step: usize) -> [(usize, usize); 4] {
 }
 
 fn find_path(
-    grid: &Vec<Vec<char>>,
+    grid: &[Vec<char>],
     start: (usize, usize),
     end: (usize, usize),
 ) -> Vec<(usize, usize)> {
@@ -59,13 +58,13 @@ fn find_path(
 
 pub fn part_one(input: &str) -> Option<u64> {
     let (grid, start, end, expected_len) = parse_input(input);
-    dbg!(&grid.len(), &grid[0].len());
-    dbg!(&start, &end);
+    // dbg!(&grid.len(), &grid[0].len());
+    // dbg!(&start, &end);
 
     let path = find_path(&grid, start, end);
     assert_eq!(path.len(), expected_len);
 
-    let cheats = find_cheats(&path, 2, &grid);
+    let cheats = find_cheats(&path, 2);
     // dbg!(&cheats
     //     .iter()
     //     .map(|(key, val)| (key, val.len()))
@@ -78,7 +77,9 @@ pub fn part_one(input: &str) -> Option<u64> {
         .to_u64()
 }
 
-fn parse_input(input: &str) -> (Vec<Vec<char>>, (usize, usize), (usize, usize), usize) {
+pub type Track = (Vec<Vec<char>>, (usize, usize), (usize, usize), usize);
+
+pub fn parse_input(input: &str) -> Track {
     let mut grid = vec![];
     let mut start = None;
     let mut end = None;
@@ -117,11 +118,9 @@ fn manhattan_distance(point_a: &(usize, usize), point_b: &(usize, usize)) -> usi
     point_a.0.abs_diff(point_b.0) + point_a.1.abs_diff(point_b.1)
 }
 
-fn find_cheats(
-    path: &[(usize, usize)],
-    allowance: usize,
-    grid: &Vec<Vec<char>>,
-) -> HashMap<usize, Vec<((usize, usize), (usize, usize))>> {
+type Cheat = ((usize, usize), (usize, usize));
+
+fn find_cheats(path: &[(usize, usize)], allowance: usize) -> HashMap<usize, Vec<Cheat>> {
     let mut cheats = HashMap::new();
     for (i, end_point) in path.iter().enumerate().skip(2) {
         for (j, start_point) in path[..i - 2].iter().enumerate() {
@@ -138,13 +137,13 @@ fn find_cheats(
 
 pub fn part_two(input: &str) -> Option<u64> {
     let (grid, start, end, expected_len) = parse_input(input);
-    dbg!(&grid.len(), &grid[0].len());
-    dbg!(&start, &end);
+    // dbg!(&grid.len(), &grid[0].len());
+    // dbg!(&start, &end);
 
     let path = find_path(&grid, start, end);
     assert_eq!(path.len(), expected_len);
 
-    let cheats = find_cheats(&path, 20, &grid);
+    let cheats = find_cheats(&path, 20);
     // dbg!(&cheats
     //     .iter()
     //     .map(|(key, val)| (key, val.len()))
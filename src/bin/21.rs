@@ -1,19 +1,21 @@
-use itertools::{iproduct, Itertools};
+use advent_of_code::memo::Memo;
+use advent_of_code::point::{Dir4, Point};
+use itertools::Itertools;
 use num::ToPrimitive;
 use std::{collections::HashMap, sync::LazyLock};
 
 advent_of_code::solution!(21);
 
-type Mapping = HashMap<char, (usize, usize)>;
+pub type Mapping = HashMap<char, (usize, usize)>;
 
 #[derive(Debug, Clone, Copy)]
-enum KeypadType {
+pub enum KeypadType {
     Numeric,
     Directional,
 }
 
 impl KeypadType {
-    fn get_mapping(&self) -> &'static Mapping {
+    pub fn get_mapping(&self) -> &'static Mapping {
         match self {
             KeypadType::Numeric => get_numeric_keypad(),
             KeypadType::Directional => get_directional_keypad(),
@@ -25,7 +27,7 @@ impl KeypadType {
     }
 }
 
-fn get_numeric_keypad() -> &'static Mapping {
+pub fn get_numeric_keypad() -> &'static Mapping {
     const LAYOUT: [(char, (usize, usize)); 11] = [
         ('7', (0, 0)),
         ('8', (0, 1)),
@@ -43,7 +45,7 @@ fn get_numeric_keypad() -> &'static Mapping {
     &KEYPAD
 }
 
-fn get_directional_keypad() -> &'static Mapping {
+pub fn get_directional_keypad() -> &'static Mapping {
     const LAYOUT: [(char, (usize, usize)); 5] = [
         ('^', (0, 1)),
         ('A', (0, 2)),
@@ -82,80 +84,76 @@ fn get_possible_sequence(
 }
 
 fn check_path(keypad_type: KeypadType, start: &(usize, usize), path: &[char]) -> bool {
-    let mut pos = start.clone();
-    for c in path {
-        match c {
-            '^' => pos.0 -= 1,
-            'v' => pos.0 += 1,
-            '<' => pos.1 -= 1,
-            '>' => pos.1 += 1,
-            _ => unreachable!(),
-        }
-        if !keypad_type.is_valid(pos) {
+    let mut pos = Point::from(*start);
+    for &c in path {
+        pos = pos.step(Dir4::from_char(c).expect("paths only contain arrows"));
+        // both keypads fit into three columns and four rows
+        if !pos
+            .to_pos(3, 4)
+            .is_some_and(|pos| keypad_type.is_valid(pos))
+        {
             return false;
         }
     }
     true
 }
 
-fn helper(input: &[char], mapping: &HashMap<(char, char), usize>) -> usize {
+/// Cost of pressing `end` after `start` on the keypad of a layer, keyed by `(layer, start, end)`.
+/// Layer 0 is pressed directly, every other layer through the layer below it, and the numeric
+/// keypad sits on top of `middle_layer_count` directional keypads.
+fn presses(middle_layer_count: usize) -> Memo<'static, (usize, char, char), usize> {
+    Memo::new(move |memo, &(layer, start, end)| {
+        let keypad_type = if layer == middle_layer_count {
+            KeypadType::Numeric
+        } else {
+            KeypadType::Directional
+        };
+        let keypad = keypad_type.get_mapping();
+        let (start_pos, end_pos) = (keypad[&start], keypad[&end]);
+        if layer == 0 {
+            return start_pos.0.abs_diff(end_pos.0) + start_pos.1.abs_diff(end_pos.1) + 1;
+        }
+        get_possible_sequence(keypad_type, &start_pos, &end_pos)
+            .into_iter()
+            .map(|seq| sequence_cost(memo, layer - 1, &seq))
+            .min()
+            .unwrap()
+    })
+}
+
+/// Cost of typing `keys` on the keypad of `layer`, starting from `A`.
+fn sequence_cost(
+    presses: &mut Memo<'_, (usize, char, char), usize>,
+    layer: usize,
+    keys: &[char],
+) -> usize {
     let mut last_key = 'A';
     let mut sum = 0;
-    for &key in input {
-        sum += mapping[&(last_key, key)];
+    for &key in keys {
+        sum += presses.get((layer, last_key, key));
         last_key = key;
     }
     sum
 }
 
-fn generate_seq_map(middle_layer_count: usize) -> HashMap<(char, char), usize> {
-    let mut keypad_types = vec![KeypadType::Directional; middle_layer_count];
-    keypad_types.push(KeypadType::Numeric);
-
-    let mut seq_length = HashMap::new();
-    let keypad = keypad_types[0].get_mapping();
-    for (&start, &end) in iproduct!(keypad.keys(), keypad.keys()) {
-        let start_pos = keypad[&start];
-        let end_pos = keypad[&end];
-        let cost = start_pos.0.abs_diff(end_pos.0) + start_pos.1.abs_diff(end_pos.1) + 1;
-        seq_length.insert((start, end), cost);
-    }
-
-    for &keypad_type in keypad_types.iter().skip(1) {
-        let mut next_seq_length = HashMap::new();
-        let keypad = keypad_type.get_mapping();
-        for (&start, &end) in iproduct!(keypad.keys(), keypad.keys()) {
-            let sequences = get_possible_sequence(keypad_type, &keypad[&start], &keypad[&end]);
-            let result = sequences
-                .into_iter()
-                .map(|seq| helper(&seq, &seq_length))
-                .min()
-                .unwrap();
-            next_seq_length.insert((start, end), result);
-        }
-        seq_length = next_seq_length;
-    }
-    seq_length
-}
-
 pub fn part_one(input: &str) -> Option<u64> {
     let codes = input.split_whitespace().collect_vec();
     let numeric_part = codes
         .iter()
         .map(|&code| code[..3].parse::<usize>().unwrap())
         .collect_vec();
-    let mapping = generate_seq_map(2);
+    let mut presses = presses(2);
 
     let sequence = codes
         .iter()
         .map(|code| code.chars().collect_vec())
-        .map(|code| helper(&code, &mapping))
+        .map(|code| sequence_cost(&mut presses, 2, &code))
         .collect_vec();
 
     sequence
         .into_iter()
         .zip(numeric_part)
-        .map(|(a, b)| dbg!(a) * dbg!(b))
+        .map(|(a, b)| a * b)
         .sum::<usize>()
         .to_u64()
 }
@@ -166,18 +164,18 @@ pub fn part_two(input: &str) -> Option<u64> {
         .iter()
         .map(|&code| code[..3].parse::<usize>().unwrap())
         .collect_vec();
-    let mapping = generate_seq_map(25);
+    let mut presses = presses(25);
 
     let sequence = codes
         .iter()
         .map(|code| code.chars().collect_vec())
-        .map(|code| helper(&code, &mapping))
+        .map(|code| sequence_cost(&mut presses, 25, &code))
         .collect_vec();
 
     sequence
         .into_iter()
         .zip(numeric_part)
-        .map(|(a, b)| dbg!(a) * dbg!(b))
+        .map(|(a, b)| a * b)
         .sum::<usize>()
         .to_u64()
 }
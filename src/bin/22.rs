@@ -21,8 +21,8 @@ fn prune(val: u64) -> u64 {
 fn next_secret(secret: u64) -> u64 {
     let secret = prune(mix(secret, secret * 64));
     let secret = prune(mix(secret, secret / 32));
-    let secret = prune(mix(secret, secret * 2048));
-    secret
+
+    prune(mix(secret, secret * 2048))
 }
 
 fn secret_iter(mut secret: u64) -> impl Iterator<Item = u64> {
@@ -9,7 +9,7 @@ advent_of_code::solution!(23);
 // Try rewire the second part with iterator or yield, like the python example in
 // https://www.geeksforgeeks.org/maximal-clique-problem-recursive-solution/
 
-fn parse<'a>(input: &'a str) -> Option<Vec<(&'a str, &'a str)>> {
+pub fn parse(input: &str) -> Option<Vec<(&str, &str)>> {
     input
         .split_whitespace()
         .map(|x| x.split("-").collect_tuple())
@@ -57,7 +57,7 @@ fn bron_kerbosch<'a>(
         p.remove(value);
         x.insert(value);
     }
-    return cliques;
+    cliques
 }
 
 // Maximal Clique Problem
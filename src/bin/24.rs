@@ -1,16 +1,11 @@
-use std::{
-    cell::RefCell,
-    collections::{HashMap, HashSet},
-    rc::Rc,
-    usize,
-};
+use std::collections::HashMap;
 
 use itertools::Itertools;
 
 advent_of_code::solution!(24);
 
 #[derive(Debug, Clone, Hash, PartialEq, Eq)]
-enum Gate {
+pub enum Gate {
     Value(bool),
     And(String, String),
     Or(String, String),
@@ -18,7 +13,7 @@ enum Gate {
 }
 
 impl Gate {
-    fn and(a: String, b: String) -> Gate {
+    pub fn and(a: String, b: String) -> Gate {
         if a < b {
             Gate::And(a, b)
         } else {
@@ -26,7 +21,7 @@ impl Gate {
         }
     }
 
-    fn or(a: String, b: String) -> Gate {
+    pub fn or(a: String, b: String) -> Gate {
         if a < b {
             Gate::Or(a, b)
         } else {
@@ -34,7 +29,7 @@ impl Gate {
         }
     }
 
-    fn xor(a: String, b: String) -> Gate {
+    pub fn xor(a: String, b: String) -> Gate {
         if a < b {
             Gate::Xor(a, b)
         } else {
@@ -43,30 +38,22 @@ impl Gate {
     }
 
     fn is_or(&self) -> bool {
-        match self {
-            Gate::Or(_, _) => true,
-            _ => false,
-        }
+        matches!(self, Gate::Or(_, _))
     }
 
     fn is_xor(&self) -> bool {
-        match self {
-            Gate::Xor(_, _) => true,
-            _ => false,
-        }
+        matches!(self, Gate::Xor(_, _))
     }
 
+    #[allow(dead_code)]
     fn is_and(&self) -> bool {
-        match self {
-            Gate::And(_, _) => true,
-            _ => false,
-        }
+        matches!(self, Gate::And(_, _))
     }
 
     fn get_val(&self) -> (&str, &str) {
         match self {
             Gate::Value(_) => unreachable!(),
-            Gate::And(a, b) | Gate::Or(a, b) | Gate::Xor(a, b) => return (a, b),
+            Gate::And(a, b) | Gate::Or(a, b) | Gate::Xor(a, b) => (a, b),
         }
     }
 
@@ -85,6 +72,7 @@ impl Gate {
         }
     }
 
+    #[allow(dead_code)]
     fn is_base_and(&self) -> bool {
         match self {
             Gate::And(a, b) => {
@@ -102,7 +90,7 @@ impl Gate {
         }
     }
 
-    fn resolve(key: String, map: &mut HashMap<String, Self>) -> Option<bool> {
+    pub fn resolve(key: String, map: &mut HashMap<String, Self>) -> Option<bool> {
         let val = match map.get(&key).cloned()? {
             Gate::Value(val) => return Some(val),
             Gate::And(a, b) => {
@@ -126,7 +114,7 @@ impl Gate {
     }
 }
 
-fn parse<'a>(input: &'a str) -> HashMap<String, Gate> {
+pub fn parse(input: &str) -> HashMap<String, Gate> {
     let mut iter = input.split("\n\n");
     let mut map = HashMap::new();
     for line in iter.next().unwrap().lines() {
@@ -222,6 +210,40 @@ pub fn part_two(input: &str) -> Option<String> {
         &mut inverse_map,
     );
 
+    // dbg!(suspicious_gates(&map, z_msb));
+
+    let mut x_val = 0u64;
+    for i in 0..=z_msb {
+        let key = std::format!("x{i:02}");
+        if let Some(val) = Gate::resolve(key, &mut map) {
+            x_val += if val { 1 << i } else { 0 };
+        }
+    }
+    let mut y_val = 0u64;
+    for i in 0..=z_msb {
+        let key = std::format!("y{i:02}");
+        if let Some(val) = Gate::resolve(key, &mut map) {
+            y_val += if val { 1 << i } else { 0 };
+        }
+    }
+    let mut z_val = 0u64;
+    for i in 0..=z_msb {
+        let key = std::format!("z{i:02}");
+        if let Some(val) = Gate::resolve(key, &mut map) {
+            z_val += if val { 1 << i } else { 0 };
+        }
+    }
+    assert_eq!(x_val + y_val, z_val);
+    let mut result = vec!["qff", "qnw", "z23", "qqp", "z36", "fbq", "z16", "pbv"];
+    result.sort();
+    Some(result.into_iter().join(","))
+}
+
+type Suspects<'a> = Vec<(String, &'a Gate)>;
+
+/// Lists the XOR gates and the outputs that do not fit the adder layout, to find the swaps.
+#[allow(dead_code)]
+fn suspicious_gates(map: &HashMap<String, Gate>, z_msb: usize) -> (Suspects<'_>, Suspects<'_>) {
     let xor_gates = map.iter().filter(|(_, val)| val.is_xor()).collect_vec();
     let non_base_xor_gates = xor_gates
         .iter()
@@ -234,18 +256,14 @@ pub fn part_two(input: &str) -> Option<String> {
         .filter(|(key, val)| {
             let target = key[1..].parse::<usize>();
             if target.is_err() {
-                return true;
+                true
             } else {
                 let (a, b) = val.get_val();
                 let x = map[a].is_base_xor();
                 let y = map[b].is_base_xor();
                 match (x, y) {
-                    (Some(i), None) if Ok(i) == target => {
-                        return i != 1 && !map[b].is_or();
-                    }
-                    (None, Some(i)) if Ok(i) == target => {
-                        return i != 1 && !map[a].is_or();
-                    }
+                    (Some(i), None) if Ok(i) == target => i != 1 && !map[b].is_or(),
+                    (None, Some(i)) if Ok(i) == target => i != 1 && !map[a].is_or(),
                     _ => {
                         // dbg!(key, &map[a], &map[b]);
                         true
@@ -256,40 +274,13 @@ pub fn part_two(input: &str) -> Option<String> {
         .cloned()
         .map(|(k, v)| (k.clone(), v))
         .collect_vec();
-    dbg!(&failed_xor);
 
     let failed_z = (0..z_msb)
         .map(|key| format!("z{key:02}"))
-        .filter(|(key)| !map[key].is_xor())
+        .filter(|key| !map[key].is_xor())
         .map(|key| (key.clone(), &map[&key]))
         .collect_vec();
-    dbg!(&failed_z);
-
-    let mut x_val = 0u64;
-    for i in 0..=z_msb {
-        let key = std::format!("x{i:02}");
-        if let Some(val) = Gate::resolve(key, &mut map) {
-            x_val += if val { 1 << i } else { 0 };
-        }
-    }
-    let mut y_val = 0u64;
-    for i in 0..=z_msb {
-        let key = std::format!("y{i:02}");
-        if let Some(val) = Gate::resolve(key, &mut map) {
-            y_val += if val { 1 << i } else { 0 };
-        }
-    }
-    let mut z_val = 0u64;
-    for i in 0..=z_msb {
-        let key = std::format!("z{i:02}");
-        if let Some(val) = Gate::resolve(key, &mut map) {
-            z_val += if val { 1 << i } else { 0 };
-        }
-    }
-    assert_eq!(x_val + y_val, z_val);
-    let mut result = vec!["qff", "qnw", "z23", "qqp", "z36", "fbq", "z16", "pbv"];
-    result.sort();
-    Some(result.into_iter().join(","))
+    (failed_xor, failed_z)
 }
 
 fn swap(
@@ -2,7 +2,9 @@ use itertools::iproduct;
 
 advent_of_code::solution!(25);
 
-fn parse(input: &str) -> Option<(Vec<[i64; 5]>, Vec<[i64; 5]>)> {
+pub type Heights = Vec<[i64; 5]>;
+
+pub fn parse(input: &str) -> Option<(Heights, Heights)> {
     let mut keys = vec![];
     let mut locks = vec![];
     let mut buffer = [0; 5];
@@ -14,7 +16,7 @@ fn parse(input: &str) -> Option<(Vec<[i64; 5]>, Vec<[i64; 5]>)> {
         }
         // as top row is filled
         // if grid.chars().take(5).all(|c| c == '#') {
-        if grid.chars().next() == Some('#') {
+        if grid.starts_with('#') {
             locks.push(buffer);
         } else {
             keys.push(buffer);
@@ -25,7 +27,7 @@ fn parse(input: &str) -> Option<(Vec<[i64; 5]>, Vec<[i64; 5]>)> {
 }
 
 pub fn part_one(input: &str) -> Option<u64> {
-    let (keys, locks) = dbg!(parse(input))?;
+    let (keys, locks) = parse(input)?;
 
     let mut count = 0;
     for (key, lock) in iproduct!(keys, locks) {
@@ -36,10 +38,6 @@ pub fn part_one(input: &str) -> Option<u64> {
     Some(count)
 }
 
-pub fn part_two(input: &str) -> Option<u64> {
-    None
-}
-
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -49,10 +47,4 @@ mod tests {
         let result = part_one(&advent_of_code::template::read_file("examples", DAY));
         assert_eq!(result, Some(3));
     }
-
-    #[test]
-    fn test_part_two() {
-        let result = part_two(&advent_of_code::template::read_file("examples", DAY));
-        assert_eq!(result, None);
-    }
 }
@@ -0,0 +1,243 @@
+//! Fixed-size sets of small integers and grid positions, stored one bit per element.
+//!
+//! For visited sets on a grid of known size, a [`BitGrid`] replaces a `HashSet<(usize, usize)>`:
+//! no hashing, one allocation, and set operations work on 64 cells at a time.
+use crate::grid::Pos;
+
+const BITS: usize = u64::BITS as usize;
+
+/// A set of the numbers `0..capacity`.
+#[derive(Debug, Clone, PartialEq, Eq, Hash, Default)]
+pub struct BitSet {
+    words: Vec<u64>,
+    capacity: usize,
+}
+
+impl BitSet {
+    pub fn new(capacity: usize) -> Self {
+        Self {
+            words: vec![0; capacity.div_ceil(BITS)],
+            capacity,
+        }
+    }
+
+    pub fn capacity(&self) -> usize {
+        self.capacity
+    }
+
+    /// Adds `i` to the set. Returns whether it was newly added.
+    ///
+    /// # Panics
+    /// If `i` is not below the capacity.
+    pub fn insert(&mut self, i: usize) -> bool {
+        assert!(
+            i < self.capacity,
+            "{i} is out of range for a bit set of {}",
+            self.capacity
+        );
+        let word = &mut self.words[i / BITS];
+        let mask = 1 << (i % BITS);
+        let new = *word & mask == 0;
+        *word |= mask;
+        new
+    }
+
+    /// Removes `i` from the set. Returns whether it was present.
+    pub fn remove(&mut self, i: usize) -> bool {
+        if !self.contains(i) {
+            return false;
+        }
+        self.words[i / BITS] &= !(1 << (i % BITS));
+        true
+    }
+
+    pub fn contains(&self, i: usize) -> bool {
+        i < self.capacity && self.words[i / BITS] & (1 << (i % BITS)) != 0
+    }
+
+    /// Returns the number of elements.
+    pub fn len(&self) -> usize {
+        self.words
+            .iter()
+            .map(|word| word.count_ones() as usize)
+            .sum()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.words.iter().all(|word| *word == 0)
+    }
+
+    pub fn clear(&mut self) {
+        self.words.fill(0);
+    }
+
+    /// Adds all elements of `other`, which must have the same capacity.
+    pub fn union_with(&mut self, other: &Self) {
+        assert_eq!(self.capacity, other.capacity, "bit sets differ in capacity");
+        for (word, other) in self.words.iter_mut().zip(&other.words) {
+            *word |= other;
+        }
+    }
+
+    /// Keeps only the elements that are in `other` as well, which must have the same capacity.
+    pub fn intersect_with(&mut self, other: &Self) {
+        assert_eq!(self.capacity, other.capacity, "bit sets differ in capacity");
+        for (word, other) in self.words.iter_mut().zip(&other.words) {
+            *word &= other;
+        }
+    }
+
+    /// Returns the elements in increasing order.
+    pub fn iter(&self) -> impl Iterator<Item = usize> + '_ {
+        self.words.iter().enumerate().flat_map(|(i, &word)| {
+            let mut rest = word;
+            std::iter::from_fn(move || {
+                (rest != 0).then(|| {
+                    let bit = rest.trailing_zeros() as usize;
+                    rest &= rest - 1;
+                    i * BITS + bit
+                })
+            })
+        })
+    }
+}
+
+/* -------------------------------------------------------------------------- */
+
+/// A set of positions in a `width` x `height` grid, see [`Grid`](crate::grid::Grid).
+#[derive(Debug, Clone, PartialEq, Eq, Hash, Default)]
+pub struct BitGrid {
+    width: usize,
+    height: usize,
+    bits: BitSet,
+}
+
+impl BitGrid {
+    pub fn new(width: usize, height: usize) -> Self {
+        Self {
+            width,
+            height,
+            bits: BitSet::new(width * height),
+        }
+    }
+
+    pub fn width(&self) -> usize {
+        self.width
+    }
+
+    pub fn height(&self) -> usize {
+        self.height
+    }
+
+    fn index(&self, (row, column): Pos) -> Option<usize> {
+        (row < self.height && column < self.width).then_some(row * self.width + column)
+    }
+
+    /// Adds `pos` to the set. Returns whether it was newly added.
+    ///
+    /// # Panics
+    /// If `pos` is outside of the grid.
+    pub fn insert(&mut self, pos: Pos) -> bool {
+        let i = self.index(pos).unwrap_or_else(|| {
+            panic!(
+                "position {pos:?} is outside of the {}x{} grid",
+                self.width, self.height
+            )
+        });
+        self.bits.insert(i)
+    }
+
+    /// Removes `pos` from the set. Returns whether it was present.
+    pub fn remove(&mut self, pos: Pos) -> bool {
+        self.index(pos).is_some_and(|i| self.bits.remove(i))
+    }
+
+    pub fn contains(&self, pos: Pos) -> bool {
+        self.index(pos).is_some_and(|i| self.bits.contains(i))
+    }
+
+    /// Returns the number of positions.
+    pub fn len(&self) -> usize {
+        self.bits.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.bits.is_empty()
+    }
+
+    pub fn clear(&mut self) {
+        self.bits.clear();
+    }
+
+    /// Adds all positions of `other`, which must have the same dimensions.
+    pub fn union_with(&mut self, other: &Self) {
+        assert_eq!(self.width, other.width, "bit grids differ in width");
+        self.bits.union_with(&other.bits);
+    }
+
+    /// Keeps only the positions that are in `other` as well, which must have the same dimensions.
+    pub fn intersect_with(&mut self, other: &Self) {
+        assert_eq!(self.width, other.width, "bit grids differ in width");
+        self.bits.intersect_with(&other.bits);
+    }
+
+    /// Returns the positions in row-major order.
+    pub fn iter(&self) -> impl Iterator<Item = Pos> + '_ {
+        self.bits.iter().map(|i| (i / self.width, i % self.width))
+    }
+}
+
+/* -------------------------------------------------------------------------- */
+
+#[cfg(feature = "test_lib")]
+mod tests {
+    use super::{BitGrid, BitSet};
+
+    #[test]
+    fn inserts_and_removes() {
+        let mut set = BitSet::new(130);
+        assert_eq!(set.insert(3), true);
+        assert_eq!(set.insert(3), false);
+        assert_eq!(set.insert(129), true);
+        assert_eq!(
+            (set.contains(3), set.contains(4), set.contains(500)),
+            (true, false, false)
+        );
+        assert_eq!(set.len(), 2);
+        assert_eq!(set.iter().collect::<Vec<_>>(), vec![3, 129]);
+        assert_eq!(set.remove(3), true);
+        assert_eq!(set.remove(3), false);
+        set.clear();
+        assert_eq!(set.is_empty(), true);
+    }
+
+    #[test]
+    fn combines_sets() {
+        let mut a = BitSet::new(100);
+        let mut b = BitSet::new(100);
+        for i in [1, 64, 70] {
+            a.insert(i);
+        }
+        for i in [64, 99] {
+            b.insert(i);
+        }
+
+        let mut union = a.clone();
+        union.union_with(&b);
+        assert_eq!(union.iter().collect::<Vec<_>>(), vec![1, 64, 70, 99]);
+        a.intersect_with(&b);
+        assert_eq!(a.iter().collect::<Vec<_>>(), vec![64]);
+    }
+
+    #[test]
+    fn indexes_positions() {
+        let mut grid = BitGrid::new(3, 2);
+        assert_eq!(grid.insert((1, 2)), true);
+        assert_eq!(grid.insert((0, 1)), true);
+        assert_eq!(grid.contains((1, 2)), true);
+        assert_eq!(grid.contains((2, 1)), false);
+        assert_eq!(grid.contains((0, 0usize.wrapping_sub(1))), false);
+        assert_eq!(grid.iter().collect::<Vec<_>>(), vec![(0, 1), (1, 2)]);
+        assert_eq!(grid.len(), 2);
+    }
+}
@@ -0,0 +1,199 @@
+//! Cycle detection for periodic simulations.
+//!
+//! A simulation that applies the same step function to a finite state space eventually repeats.
+//! Once the [`Cycle`] is known, step `n` of the simulation can be looked up instead of simulated,
+//! and a search over steps can stop after one period instead of running forever.
+use std::collections::HashMap;
+use std::error::Error;
+use std::fmt::Display;
+use std::hash::Hash;
+
+use crate::math::crt;
+
+/// The shape of the sequence `x0, f(x0), f(f(x0)), ...`: after `start` steps it repeats every
+/// `length` steps.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Cycle {
+    pub start: usize,
+    pub length: usize,
+}
+
+impl Cycle {
+    /// Returns the earliest step with the same state as step `n`.
+    pub fn reduce(&self, n: usize) -> usize {
+        if n < self.start {
+            n
+        } else {
+            self.start + (n - self.start) % self.length
+        }
+    }
+}
+
+/// Finds the cycle with Floyd's tortoise and hare, storing only two states.
+pub fn floyd<T: PartialEq>(x0: T, f: impl Fn(&T) -> T) -> Cycle {
+    let mut tortoise = f(&x0);
+    let mut hare = f(&tortoise);
+    while tortoise != hare {
+        tortoise = f(&tortoise);
+        hare = f(&f(&hare));
+    }
+
+    let mut start = 0;
+    tortoise = x0;
+    while tortoise != hare {
+        tortoise = f(&tortoise);
+        hare = f(&hare);
+        start += 1;
+    }
+
+    let mut length = 1;
+    hare = f(&tortoise);
+    while tortoise != hare {
+        hare = f(&hare);
+        length += 1;
+    }
+    Cycle { start, length }
+}
+
+/// Finds the cycle with Brent's algorithm, which needs fewer calls of `f` than [`floyd`].
+pub fn brent<T: PartialEq + Clone>(x0: T, f: impl Fn(&T) -> T) -> Cycle {
+    let mut power = 1;
+    let mut length = 1;
+    let mut tortoise = x0.clone();
+    let mut hare = f(&x0);
+    while tortoise != hare {
+        if power == length {
+            tortoise = hare.clone();
+            power *= 2;
+            length = 0;
+        }
+        hare = f(&hare);
+        length += 1;
+    }
+
+    tortoise = x0.clone();
+    hare = x0;
+    for _ in 0..length {
+        hare = f(&hare);
+    }
+    let mut start = 0;
+    while tortoise != hare {
+        tortoise = f(&tortoise);
+        hare = f(&hare);
+        start += 1;
+    }
+    Cycle { start, length }
+}
+
+/// Finds the cycle by remembering every state, and returns the states up to its first repetition.
+/// `states[cycle.reduce(n)]` is the state after `n` steps.
+pub fn find_cycle<T: Eq + Hash + Clone>(x0: T, mut f: impl FnMut(&T) -> T) -> (Cycle, Vec<T>) {
+    let mut seen = HashMap::from([(x0.clone(), 0)]);
+    let mut states = vec![x0];
+    loop {
+        let next = f(states.last().unwrap());
+        if let Some(&start) = seen.get(&next) {
+            let cycle = Cycle {
+                start,
+                length: states.len() - start,
+            };
+            return (cycle, states);
+        }
+        seen.insert(next.clone(), states.len());
+        states.push(next);
+    }
+}
+
+/* -------------------------------------------------------------------------- */
+
+/// No step of a periodic simulation satisfies a condition.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct NoneInPeriod {
+    pub start: usize,
+    pub period: usize,
+}
+
+impl Display for NoneInPeriod {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(
+            f,
+            "no step in {}..{} matches, and the simulation repeats every {} steps",
+            self.start,
+            self.start + self.period,
+            self.period
+        )
+    }
+}
+
+impl Error for NoneInPeriod {}
+
+/// Returns the first step from `start` on for which `matches` is true, in a simulation that
+/// repeats every `period` steps. Fails after checking one full period.
+pub fn first_in_period(
+    start: usize,
+    period: usize,
+    matches: impl FnMut(&usize) -> bool,
+) -> Result<usize, NoneInPeriod> {
+    (start..start + period)
+        .find(matches)
+        .ok_or(NoneInPeriod { start, period })
+}
+
+/// Returns the first step at which independent periodic events coincide.
+///
+/// Each event is `(first, period)`: it happens at step `first` and every `period` steps after it.
+/// Returns `None` if the events never happen at the same step.
+pub fn first_coincidence(events: &[(i64, i64)]) -> Option<i64> {
+    let (residue, period) = crt(events.iter().copied())?;
+    let earliest = events.iter().map(|(first, _)| *first).max().unwrap_or(0);
+    // the smallest step >= earliest congruent to the residue
+    Some(earliest + (residue - earliest).rem_euclid(period))
+}
+
+/* -------------------------------------------------------------------------- */
+
+#[cfg(feature = "test_lib")]
+mod tests {
+    use super::{brent, find_cycle, first_coincidence, first_in_period, floyd, Cycle};
+
+    /// 3 -> 10 -> 101 -> 2 -> 5 -> 26 -> 167 -> 95 -> 101 -> ...
+    fn step(x: &u64) -> u64 {
+        (x * x + 1) % 255
+    }
+
+    #[test]
+    fn detects_cycles() {
+        let expected = Cycle {
+            start: 2,
+            length: 6,
+        };
+        assert_eq!(floyd(3, step), expected);
+        assert_eq!(brent(3, step), expected);
+
+        let (cycle, states) = find_cycle(3, step);
+        assert_eq!(cycle, expected);
+        assert_eq!(states, vec![3, 10, 101, 2, 5, 26, 167, 95]);
+        let mut x = 3;
+        for n in 0..20 {
+            assert_eq!(states[cycle.reduce(n)], x);
+            x = step(&x);
+        }
+    }
+
+    #[test]
+    fn bounds_searches_by_the_period() {
+        assert_eq!(first_in_period(1, 10, |t| t * t % 10 == 6), Ok(4));
+        let err = first_in_period(1, 10, |t| *t > 100);
+        assert_eq!(
+            err.unwrap_err().to_string(),
+            "no step in 1..11 matches, and the simulation repeats every 10 steps"
+        );
+    }
+
+    #[test]
+    fn finds_coincidences() {
+        assert_eq!(first_coincidence(&[(2, 3), (3, 5)]), Some(8));
+        assert_eq!(first_coincidence(&[(20, 3), (3, 5)]), Some(23));
+        assert_eq!(first_coincidence(&[(1, 4), (2, 6)]), None);
+    }
+}
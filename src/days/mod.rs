@@ -0,0 +1,126 @@
+//! Every day's solution, compiled into the library from its binary `src/bin/<day>.rs`.
+//!
+//! Each `dayNN` module has the same public API, set up by [`crate::solution`]:
+//!  - `DAY`: the day of the module.
+//!  - `part_one` and `part_two`: the solutions. A part the day does not solve returns `Option<NotImplemented>`.
+//!  - `fuzz_targets`: the day's differential tests, see [`crate::fuzz`].
+//!
+//! Anything else a day declares `pub` is available as well.
+//! The list at the bottom names every day that has a binary, `cargo scaffold` adds new days to it.
+//! Days with a standalone parser name it in the list as well, so it is benchmarked separately.
+use std::any::TypeId;
+use std::fmt::Display;
+use std::hint::black_box;
+
+use crate::template::runner::NotImplemented;
+use crate::template::Day;
+
+type Part = Box<dyn Fn(&str) -> Option<String>>;
+type Parser = Box<dyn Fn(&str)>;
+
+/// A day's solution with its answers converted to strings, see [`solutions`].
+pub struct Solution {
+    pub day: Day,
+    /// The input parser of the day, if it has a standalone one.
+    /// Its result is discarded, so that it can be benchmarked on its own.
+    pub parse: Option<Parser>,
+    /// The parts of the solution, `None` if the day does not solve a part.
+    pub parts: [Option<Part>; 2],
+}
+
+fn erase<T: Display + 'static>(func: fn(&str) -> Option<T>) -> Option<Part> {
+    if TypeId::of::<T>() == TypeId::of::<NotImplemented>() {
+        return None;
+    }
+    Some(Box::new(move |input| {
+        func(input).map(|answer| answer.to_string())
+    }))
+}
+
+macro_rules! days {
+    (@parse $module:ident) => {
+        None
+    };
+    (@parse $module:ident $($parse:ident)::+) => {
+        Some(Box::new(|input: &str| {
+            let _ = black_box($module::$($parse)::+(input));
+        }))
+    };
+
+    ($($module:ident: $path:literal $(=> $($parse:ident)::+)?,)*) => {
+        $(
+            #[path = $path]
+            pub mod $module;
+        )*
+
+        /// Returns the solutions of all days, in order.
+        pub fn solutions() -> Vec<Solution> {
+            vec![$(
+                Solution {
+                    day: $module::DAY,
+                    parse: days!(@parse $module $($($parse)::+)?),
+                    parts: [erase($module::part_one), erase($module::part_two)],
+                },
+            )*]
+        }
+    };
+}
+
+days! {
+    day01: "../bin/01.rs" => parse_input,
+    day02: "../bin/02.rs" => parse_reports,
+    day03: "../bin/03.rs",
+    day04: "../bin/04.rs",
+    day05: "../bin/05.rs" => parse,
+    day06: "../bin/06.rs",
+    day07: "../bin/07.rs",
+    day08: "../bin/08.rs",
+    day09: "../bin/09.rs",
+    day10: "../bin/10.rs" => parse_grid,
+    day11: "../bin/11.rs",
+    day12: "../bin/12.rs",
+    day13: "../bin/13.rs",
+    day14: "../bin/14.rs" => parse_input,
+    day15: "../bin/15.rs",
+    day16: "../bin/16.rs" => parse_input,
+    day17: "../bin/17.rs" => Computer::parse_from,
+    day18: "../bin/18.rs" => parse_input,
+    day19: "../bin/19.rs" => parse_input,
+    day20: "../bin/20.rs" => parse_input,
+    day21: "../bin/21.rs",
+    day22: "../bin/22.rs",
+    day23: "../bin/23.rs" => parse,
+    day24: "../bin/24.rs" => parse,
+    day25: "../bin/25.rs" => parse,
+}
+
+/* -------------------------------------------------------------------------- */
+
+#[cfg(feature = "test_lib")]
+mod tests {
+    use super::{day01, day17, solutions};
+    use crate::template::read_file;
+
+    #[test]
+    fn registers_every_day() {
+        let solutions = solutions();
+        assert_eq!(solutions.len(), 25);
+        for (i, solution) in solutions.iter().enumerate() {
+            assert_eq!(solution.day.into_inner(), i as u8 + 1);
+        }
+        assert_eq!(solutions[24].parts[1].is_none(), true);
+    }
+
+    #[test]
+    fn erases_answers() {
+        let input = read_file("examples", day01::DAY);
+        let part_one = solutions()[0].parts[0].as_ref().unwrap()(&input);
+        assert_eq!(part_one, day01::part_one(&input).map(|x| x.to_string()));
+    }
+
+    #[test]
+    fn exposes_helpers() {
+        let mut computer = day17::Computer::new([10, 0, 0], vec![5, 0, 5, 1, 5, 4]);
+        assert_eq!(computer.run_until_halts(), vec![0, 1, 2]);
+    }
+}
@@ -0,0 +1,199 @@
+//! Differential testing of solutions against a reference implementation.
+//!
+//! A day registers [`Target`]s by defining a `fuzz_targets` function next to its parts.
+//! `cargo fuzz-day <day>` then generates random inputs for every target and checks that the
+//! reference and the candidate implementation agree on all of them.
+use std::fmt::{Debug, Display};
+use std::ops::RangeInclusive;
+use std::panic::{self, AssertUnwindSafe};
+
+/// A small, seedable pseudo-random number generator (SplitMix64).
+/// Fuzz runs are reproducible from their seed.
+#[derive(Debug, Clone)]
+pub struct Rng {
+    state: u64,
+}
+
+impl Rng {
+    pub fn new(seed: u64) -> Self {
+        Self { state: seed }
+    }
+
+    pub fn next_u64(&mut self) -> u64 {
+        self.state = self.state.wrapping_add(0x9e37_79b9_7f4a_7c15);
+        let mut z = self.state;
+        z = (z ^ (z >> 30)).wrapping_mul(0xbf58_476d_1ce4_e5b9);
+        z = (z ^ (z >> 27)).wrapping_mul(0x94d0_49bb_1331_11eb);
+        z ^ (z >> 31)
+    }
+
+    /// Returns a number in `0..n`.
+    pub fn below(&mut self, n: usize) -> usize {
+        assert!(n > 0, "cannot pick a number below 0");
+        (self.next_u64() % n as u64) as usize
+    }
+
+    /// Returns a number in the inclusive `range`.
+    pub fn range(&mut self, range: RangeInclusive<i64>) -> i64 {
+        let (start, end) = range.into_inner();
+        assert!(start <= end, "cannot pick a number from an empty range");
+        let span = end.abs_diff(start) + 1;
+        start.wrapping_add_unsigned(self.next_u64() % span)
+    }
+
+    /// Returns `true` with a probability of `p`.
+    pub fn chance(&mut self, p: f64) -> bool {
+        ((self.next_u64() >> 11) as f64 / (1u64 << 53) as f64) < p
+    }
+
+    /// Returns a random element of `items`.
+    pub fn choose<'a, T>(&mut self, items: &'a [T]) -> &'a T {
+        &items[self.below(items.len())]
+    }
+
+    /// Shuffles `items` in place (Fisher-Yates).
+    pub fn shuffle<T>(&mut self, items: &mut [T]) {
+        for i in (1..items.len()).rev() {
+            items.swap(i, self.below(i + 1));
+        }
+    }
+}
+
+/* -------------------------------------------------------------------------- */
+
+type Solver = Box<dyn Fn(&str) -> String>;
+
+/// A generator of puzzle inputs together with two implementations that must agree on them.
+pub struct Target {
+    pub name: &'static str,
+    generate: Box<dyn Fn(&mut Rng) -> String>,
+    reference: Solver,
+    candidate: Solver,
+}
+
+impl Target {
+    /// Creates a target. Answers are compared by their `Debug` representation.
+    pub fn new<T: Debug>(
+        name: &'static str,
+        generate: impl Fn(&mut Rng) -> String + 'static,
+        reference: impl Fn(&str) -> T + 'static,
+        candidate: impl Fn(&str) -> T + 'static,
+    ) -> Self {
+        Self {
+            name,
+            generate: Box::new(generate),
+            reference: Box::new(move |input| format!("{:?}", reference(input))),
+            candidate: Box::new(move |input| format!("{:?}", candidate(input))),
+        }
+    }
+
+    /// Checks `iterations` inputs, generated from the seeds `seed`, `seed + 1`, ...
+    pub fn check(&self, seed: u64, iterations: u64) -> Result<(), Failure> {
+        (0..iterations).try_for_each(|i| self.check_one(seed.wrapping_add(i)))
+    }
+
+    fn check_one(&self, seed: u64) -> Result<(), Failure> {
+        let input = (self.generate)(&mut Rng::new(seed));
+        let solve = |solver: &Solver| {
+            panic::catch_unwind(AssertUnwindSafe(|| solver(&input)))
+                .unwrap_or_else(|_| "<panicked>".into())
+        };
+
+        let expected = solve(&self.reference);
+        let found = solve(&self.candidate);
+
+        if expected == found {
+            Ok(())
+        } else {
+            Err(Failure {
+                target: self.name,
+                seed,
+                input,
+                expected,
+                found,
+            })
+        }
+    }
+}
+
+/// An input on which the implementations of a [`Target`] disagree.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Failure {
+    pub target: &'static str,
+    /// Seed that reproduces the input, e.g. with `cargo fuzz-day <day> --seed <seed> --iterations 1`.
+    pub seed: u64,
+    pub input: String,
+    pub expected: String,
+    pub found: String,
+}
+
+impl Display for Failure {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        writeln!(f, "{} disagrees for seed {}:", self.target, self.seed)?;
+        writeln!(f, "  reference: {}", self.expected)?;
+        writeln!(f, "  candidate: {}", self.found)?;
+        writeln!(f, "input:")?;
+        write!(f, "{}", self.input.trim_end())
+    }
+}
+
+/* -------------------------------------------------------------------------- */
+
+#[cfg(feature = "test_lib")]
+mod tests {
+    use super::{Rng, Target};
+
+    fn generate(rng: &mut Rng) -> String {
+        (0..5)
+            .map(|_| rng.range(-10..=10).to_string())
+            .collect::<Vec<_>>()
+            .join(" ")
+    }
+
+    fn sum(input: &str) -> i64 {
+        input
+            .split_whitespace()
+            .map(|x| x.parse::<i64>().unwrap())
+            .sum()
+    }
+
+    #[test]
+    fn rng_is_reproducible() {
+        let a: Vec<_> = (0..5).map(|_| Rng::new(7).next_u64()).collect();
+        assert_eq!(a.iter().all(|x| *x == a[0]), true);
+
+        let mut rng = Rng::new(7);
+        for _ in 0..1000 {
+            assert_eq!((-3..=3).contains(&rng.range(-3..=3)), true);
+            assert_eq!(rng.below(4) < 4, true);
+        }
+    }
+
+    #[test]
+    fn passes_for_agreeing_implementations() {
+        let target = Target::new("sum", generate, sum, |input: &str| {
+            input
+                .split_whitespace()
+                .fold(0, |acc, x| acc + x.parse::<i64>().unwrap())
+        });
+        assert_eq!(target.check(0, 200), Ok(()));
+    }
+
+    #[test]
+    fn reports_disagreement() {
+        let target = Target::new("sum", generate, sum, |input: &str| sum(input).max(0));
+        let failure = target.check(0, 200).unwrap_err();
+        assert_eq!(failure.found, "0");
+        assert_eq!(failure.expected.starts_with('-'), true);
+
+        let again = target.check(failure.seed, 1).unwrap_err();
+        assert_eq!(again, failure);
+    }
+
+    #[test]
+    fn reports_panics() {
+        let target = Target::new("sum", generate, sum, |_: &str| -> i64 { panic!("oops") });
+        let failure = target.check(0, 1).unwrap_err();
+        assert_eq!(failure.found, "<panicked>");
+    }
+}
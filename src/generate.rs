@@ -0,0 +1,844 @@
+//! Synthetic puzzle inputs for stress-testing and benchmarking solutions.
+//!
+//! Every day has a [`Generator`] that produces a valid puzzle of a configurable size from a seed,
+//! e.g. with `cargo gen-input 16 --size 301 --seed 7`. What the size measures depends on the puzzle,
+//! see [`Generator::size`].
+use std::collections::{HashSet, VecDeque};
+
+use itertools::Itertools;
+
+use crate::fuzz::Rng;
+use crate::template::Day;
+
+/// An input generator for the puzzle of a day.
+#[derive(Debug, Clone, Copy)]
+pub struct Generator {
+    /// What the size of an input measures, e.g. `lines` or `grid side`.
+    pub size: &'static str,
+    /// The size of a real puzzle input.
+    pub default_size: usize,
+    pub min_size: usize,
+    pub max_size: usize,
+    generate: fn(&mut Rng, usize) -> String,
+}
+
+impl Generator {
+    /// Generates an input from `seed`. The size is clamped to `min_size..=max_size`.
+    pub fn generate(&self, size: usize, seed: u64) -> String {
+        let size = size.clamp(self.min_size, self.max_size);
+        let mut input = (self.generate)(&mut Rng::new(seed), size);
+        input.push('\n');
+        input
+    }
+}
+
+const fn generator(
+    size: &'static str,
+    default_size: usize,
+    (min_size, max_size): (usize, usize),
+    generate: fn(&mut Rng, usize) -> String,
+) -> Generator {
+    Generator {
+        size,
+        default_size,
+        min_size,
+        max_size,
+        generate,
+    }
+}
+
+const GENERATORS: [Generator; 25] = [
+    generator("lines", 1000, (1, usize::MAX), location_ids),
+    generator("reports", 1000, (1, usize::MAX), reports),
+    generator("instructions", 700, (1, usize::MAX), corrupted_memory),
+    generator("grid side", 140, (1, usize::MAX), word_search),
+    generator("updates", 200, (1, usize::MAX), print_queue),
+    generator("grid side", 130, (2, usize::MAX), guard_map),
+    generator("equations", 850, (1, usize::MAX), calibrations),
+    generator("grid side", 50, (4, usize::MAX), antenna_map),
+    generator("digits", 19_999, (1, usize::MAX), disk_map),
+    generator("grid side", 57, (2, usize::MAX), topographic_map),
+    generator("stones", 8, (1, usize::MAX), stones),
+    generator("grid side", 140, (1, usize::MAX), garden),
+    generator("claw machines", 320, (1, usize::MAX), claw_machines),
+    generator("robots", 500, (1, ROBOT_SPACE.0 * ROBOT_SPACE.1), robots),
+    generator("grid side", 50, (3, usize::MAX), warehouse),
+    generator("grid side", 141, (5, usize::MAX), reindeer_maze),
+    generator("octal digits of register A", 16, (1, 21), computer),
+    generator(
+        "bytes",
+        3450,
+        (BYTES_FALLEN, MEMORY_SIDE * MEMORY_SIDE - 2),
+        falling_bytes,
+    ),
+    generator("designs", 400, (1, usize::MAX), towels),
+    generator("grid side", 141, (5, usize::MAX), race_track),
+    generator("codes", 5, (1, 1000), door_codes),
+    generator("buyers", 2000, (1, usize::MAX), secrets),
+    generator("computers", 520, (2, 26 * 26), lan_party),
+    generator("bits", 45, (6, 62), adder),
+    generator("schematics", 500, (1, usize::MAX), schematics),
+];
+
+/// Returns the input generator for the puzzle of `day`.
+pub fn generator_for(day: Day) -> Generator {
+    GENERATORS[usize::from(day.into_inner()) - 1]
+}
+
+/* -------------------------------------------------------------------------- */
+
+fn render(grid: &[Vec<u8>]) -> String {
+    grid.iter()
+        .map(|row| String::from_utf8_lossy(row))
+        .join("\n")
+}
+
+fn get(grid: &[Vec<u8>], (r, c): (i64, i64)) -> Option<u8> {
+    let row = grid.get(usize::try_from(r).ok()?)?;
+    row.get(usize::try_from(c).ok()?).copied()
+}
+
+const NEIGHBOURS: [(i64, i64); 4] = [(-1, 0), (0, 1), (1, 0), (0, -1)];
+
+/// Carves a perfect maze into a grid of walls with a randomized depth-first search.
+/// Open cells have odd coordinates, `side` must be odd.
+fn maze(rng: &mut Rng, side: usize) -> Vec<Vec<u8>> {
+    let mut grid = vec![vec![b'#'; side]; side];
+    let mut stack = vec![(1, 1)];
+    grid[1][1] = b'.';
+
+    while let Some(&(r, c)) = stack.last() {
+        let next = NEIGHBOURS
+            .iter()
+            .map(|(dr, dc)| (r + 2 * dr, c + 2 * dc))
+            .filter(|&pos| {
+                pos.0 > 0
+                    && pos.1 > 0
+                    && pos.0 < side as i64 - 1
+                    && pos.1 < side as i64 - 1
+                    && get(&grid, pos) == Some(b'#')
+            })
+            .collect_vec();
+
+        if next.is_empty() {
+            stack.pop();
+        } else {
+            let (nr, nc) = *rng.choose(&next);
+            grid[((r + nr) / 2) as usize][((c + nc) / 2) as usize] = b'.';
+            grid[nr as usize][nc as usize] = b'.';
+            stack.push((nr, nc));
+        }
+    }
+
+    grid
+}
+
+/* -------------------------------------------------------------------------- */
+
+fn location_ids(rng: &mut Rng, lines: usize) -> String {
+    let left = (0..lines).map(|_| rng.range(10_000..=99_999)).collect_vec();
+    left.iter()
+        .map(|l| {
+            let r = if rng.chance(0.3) {
+                *rng.choose(&left)
+            } else {
+                rng.range(10_000..=99_999)
+            };
+            format!("{l}   {r}")
+        })
+        .join("\n")
+}
+
+/// Mostly monotonic reports of 5 to 8 levels, half of them with one level off.
+fn reports(rng: &mut Rng, reports: usize) -> String {
+    (0..reports)
+        .map(|_| {
+            let step = *rng.choose(&[-1, 1]);
+            let mut level = rng.range(30..=70);
+            let mut levels = vec![];
+            for _ in 0..rng.range(5..=8) {
+                levels.push(level);
+                level += step * rng.range(1..=3);
+            }
+            if rng.chance(0.5) {
+                let i = rng.below(levels.len());
+                levels[i] += rng.range(-4..=4);
+            }
+            levels.iter().join(" ")
+        })
+        .join("\n")
+}
+
+fn corrupted_memory(rng: &mut Rng, instructions: usize) -> String {
+    const NOISE: [&str; 24] = [
+        "what()", "from()", "select()", "where()", "how()", "who()", "mul[", "mul(", ")", "don't",
+        "do(", "#", "!", "@", "^", "&", "*", "%", "$", "'", "<", ">", "?", " ",
+    ];
+
+    let mut memory = String::new();
+    for i in 0..instructions {
+        let (x, y) = (rng.range(1..=999), rng.range(1..=999));
+        let instruction = match rng.below(10) {
+            0 => "do()".to_string(),
+            1 => "don't()".to_string(),
+            2 => format!("mul({x},{y}]"),
+            3 => format!("mul ( {x} , {y} )"),
+            4 => format!("mul({x}*"),
+            _ => format!("mul({x},{y})"),
+        };
+        memory.push_str(&instruction);
+        for _ in 0..rng.below(4) {
+            let noise = rng.choose(&NOISE);
+            memory.push_str(noise);
+        }
+        if (i + 1) % 120 == 0 {
+            memory.push('\n');
+        }
+    }
+    memory.trim_end().to_string()
+}
+
+fn word_search(rng: &mut Rng, side: usize) -> String {
+    let grid = (0..side)
+        .map(|_| (0..side).map(|_| *rng.choose(b"XMAS")).collect())
+        .collect_vec();
+    render(&grid)
+}
+
+/// Pages are ordered by a random permutation and every pair of them has a rule.
+fn print_queue(rng: &mut Rng, updates: usize) -> String {
+    let mut pages = (10..=99).collect_vec();
+    rng.shuffle(&mut pages);
+    pages.truncate(49);
+
+    let mut rules = pages
+        .iter()
+        .tuple_combinations()
+        .map(|(before, after)| format!("{before}|{after}"))
+        .collect_vec();
+    rng.shuffle(&mut rules);
+
+    let updates = (0..updates).map(|_| {
+        let mut indices = (0..pages.len()).collect_vec();
+        rng.shuffle(&mut indices);
+        indices.truncate(2 * rng.below(10) + 5);
+        if rng.chance(0.5) {
+            indices.sort_unstable();
+        }
+        indices.iter().map(|&i| pages[i]).join(",")
+    });
+
+    format!("{}\n\n{}", rules.join("\n"), updates.format("\n"))
+}
+
+/// Returns the number of steps until the guard walks off the map, or `None` if they get stuck in a loop.
+fn guard_steps(grid: &[Vec<u8>], start: (i64, i64)) -> Option<usize> {
+    let (mut pos, mut dir) = (start, 0);
+    let mut seen = HashSet::new();
+
+    while seen.insert((pos, dir)) {
+        let next = (pos.0 + NEIGHBOURS[dir].0, pos.1 + NEIGHBOURS[dir].1);
+        match get(grid, next) {
+            None => return Some(seen.len()),
+            Some(b'#') => dir = (dir + 1) % 4,
+            Some(_) => pos = next,
+        }
+    }
+    None
+}
+
+/// The guard starts on the longest of a few random walks that leave the map.
+fn guard_map(rng: &mut Rng, side: usize) -> String {
+    loop {
+        let mut grid = (0..side)
+            .map(|_| {
+                (0..side)
+                    .map(|_| if rng.chance(0.05) { b'#' } else { b'.' })
+                    .collect_vec()
+            })
+            .collect_vec();
+
+        let start = (0..50)
+            .map(|_| (rng.below(side) as i64, rng.below(side) as i64))
+            .filter(|&pos| get(&grid, pos) == Some(b'.'))
+            .filter_map(|pos| Some((guard_steps(&grid, pos)?, pos)))
+            .max();
+
+        if let Some((_, (r, c))) = start {
+            grid[r as usize][c as usize] = b'^';
+            return render(&grid);
+        }
+    }
+}
+
+/// Half of the equations can be made true, values are small enough for all operators to fit a `u64`.
+fn calibrations(rng: &mut Rng, equations: usize) -> String {
+    (0..equations)
+        .map(|_| {
+            let values = (0..rng.range(2..=9))
+                .map(|_| rng.range(1..=99) as u64)
+                .collect_vec();
+            let mut target = values[1..]
+                .iter()
+                .fold(values[0], |acc, &x| match rng.below(3) {
+                    0 => acc + x,
+                    1 => acc * x,
+                    _ => acc * 10u64.pow(x.ilog10() + 1) + x,
+                });
+            if rng.chance(0.5) {
+                target += rng.range(1..=9) as u64;
+            }
+            format!("{target}: {}", values.iter().join(" "))
+        })
+        .join("\n")
+}
+
+fn antenna_map(rng: &mut Rng, side: usize) -> String {
+    const FREQUENCIES: &[u8] = b"0123456789abcdefghijklmnopqrstuvwxyzABCDEFGHIJKLMNOPQRSTUVWXYZ";
+
+    let mut grid = vec![vec![b'.'; side]; side];
+    for &frequency in &FREQUENCIES[..(side / 3).clamp(1, FREQUENCIES.len())] {
+        for _ in 0..rng.range(3..=4) {
+            loop {
+                let (r, c) = (rng.below(side), rng.below(side));
+                if grid[r][c] == b'.' {
+                    grid[r][c] = frequency;
+                    break;
+                }
+            }
+        }
+    }
+    render(&grid)
+}
+
+/// The number of digits is rounded up to be odd, so that the map ends with a file.
+fn disk_map(rng: &mut Rng, digits: usize) -> String {
+    (0..digits | 1)
+        .map(|i| {
+            let min = if i % 2 == 0 { 1 } else { 0 };
+            rng.range(min..=9).to_string()
+        })
+        .collect()
+}
+
+/// Heights rise towards random peaks and drop back to 0 every ten steps away from them,
+/// some cells are noise that break up the trails.
+fn topographic_map(rng: &mut Rng, side: usize) -> String {
+    let peaks = (0..(side * side / 40).max(1))
+        .map(|_| (rng.below(side) as i64, rng.below(side) as i64))
+        .collect_vec();
+
+    let grid = (0..side as i64)
+        .map(|r| {
+            (0..side as i64)
+                .map(|c| {
+                    let distance = peaks
+                        .iter()
+                        .map(|(pr, pc)| (pr - r).abs() + (pc - c).abs())
+                        .min()
+                        .unwrap();
+                    let height = if rng.chance(0.1) {
+                        rng.below(10)
+                    } else {
+                        9 - distance as usize % 10
+                    };
+                    b'0' + height as u8
+                })
+                .collect_vec()
+        })
+        .collect_vec();
+    render(&grid)
+}
+
+fn stones(rng: &mut Rng, stones: usize) -> String {
+    (0..stones)
+        .map(|_| {
+            let digits = rng.range(1..=7) as u32;
+            let min = if digits == 1 {
+                0
+            } else {
+                10i64.pow(digits - 1)
+            };
+            rng.range(min..=10i64.pow(digits) - 1)
+        })
+        .join(" ")
+}
+
+/// Regions grow around random seeds, the noisy distance makes their borders ragged.
+fn garden(rng: &mut Rng, side: usize) -> String {
+    let seeds = (0..(side * side / 60).max(1))
+        .map(|_| {
+            let plant = b'A' + rng.below(26) as u8;
+            (rng.below(side) as i64, rng.below(side) as i64, plant)
+        })
+        .collect_vec();
+
+    let grid = (0..side as i64)
+        .map(|r| {
+            (0..side as i64)
+                .map(|c| {
+                    let (_, _, plant) = seeds
+                        .iter()
+                        .min_by_key(|(sr, sc, _)| {
+                            (sr - r).abs() + (sc - c).abs() + rng.range(0..=2)
+                        })
+                        .unwrap();
+                    *plant
+                })
+                .collect_vec()
+        })
+        .collect_vec();
+    render(&grid)
+}
+
+/// About 60% of the prizes can be won, the buttons of a machine are never collinear.
+fn claw_machines(rng: &mut Rng, machines: usize) -> String {
+    (0..machines)
+        .map(|_| {
+            let (a, b) = loop {
+                let a = (rng.range(10..=99), rng.range(10..=99));
+                let b = (rng.range(10..=99), rng.range(10..=99));
+                if a.0 * b.1 != a.1 * b.0 {
+                    break (a, b);
+                }
+            };
+            let prize = if rng.chance(0.6) {
+                let (na, nb) = (rng.range(1..=100), rng.range(1..=100));
+                (a.0 * na + b.0 * nb, a.1 * na + b.1 * nb)
+            } else {
+                (rng.range(1_000..=20_000), rng.range(1_000..=20_000))
+            };
+            format!(
+                "Button A: X+{}, Y+{}\nButton B: X+{}, Y+{}\nPrize: X={}, Y={}",
+                a.0, a.1, b.0, b.1, prize.0, prize.1
+            )
+        })
+        .join("\n\n")
+}
+
+const ROBOT_SPACE: (usize, usize) = (101, 103);
+
+/// The robots form a framed christmas tree at a random second, where no two of them overlap.
+fn robots(rng: &mut Rng, robots: usize) -> String {
+    let (width, height) = (ROBOT_SPACE.0 as i64, ROBOT_SPACE.1 as i64);
+    let (left, top) = (rng.range(0..=width - 31), rng.range(0..=height - 33));
+
+    let frame = (0..31)
+        .flat_map(|x| [(x, 0), (x, 32)])
+        .chain((1..32).flat_map(|y| [(0, y), (30, y)]));
+    let tree = (0..15).flat_map(|y| (15 - y..=15 + y).map(move |x| (x, y + 4)));
+    let mut positions = frame
+        .chain(tree)
+        .map(|(x, y)| (left + x, top + y))
+        .take(robots)
+        .collect_vec();
+
+    let mut taken: HashSet<_> = positions.iter().copied().collect();
+    while positions.len() < robots {
+        let pos = (rng.range(0..=width - 1), rng.range(0..=height - 1));
+        if taken.insert(pos) {
+            positions.push(pos);
+        }
+    }
+    rng.shuffle(&mut positions);
+
+    let seconds = rng.range(100..=width * height - 1);
+    positions
+        .into_iter()
+        .map(|(x, y)| {
+            let v = (rng.range(-99..=99), rng.range(-99..=99));
+            let p = (
+                (x - v.0 * seconds).rem_euclid(width),
+                (y - v.1 * seconds).rem_euclid(height),
+            );
+            format!("p={},{} v={},{}", p.0, p.1, v.0, v.1)
+        })
+        .join("\n")
+}
+
+fn warehouse(rng: &mut Rng, side: usize) -> String {
+    let mut grid = (0..side)
+        .map(|r| {
+            (0..side)
+                .map(|c| {
+                    if r == 0 || c == 0 || r == side - 1 || c == side - 1 || rng.chance(0.08) {
+                        b'#'
+                    } else if rng.chance(0.3) {
+                        b'O'
+                    } else {
+                        b'.'
+                    }
+                })
+                .collect_vec()
+        })
+        .collect_vec();
+    grid[side / 2][side / 2] = b'@';
+
+    let moves = (0..8 * side * side)
+        .map(|_| *rng.choose(&['^', '>', 'v', '<']))
+        .chunks(1000)
+        .into_iter()
+        .map(|line| line.collect::<String>())
+        .join("\n");
+
+    format!("{}\n\n{moves}", render(&grid))
+}
+
+/// A maze with a few loops. The side is rounded up to be odd.
+fn reindeer_maze(rng: &mut Rng, side: usize) -> String {
+    let side = side | 1;
+    let mut grid = maze(rng, side);
+
+    // walls between two cells have exactly one even coordinate.
+    for (r, row) in grid.iter_mut().enumerate().take(side - 1).skip(1) {
+        for (c, cell) in row.iter_mut().enumerate().take(side - 1).skip(1) {
+            if (r + c) % 2 == 1 && rng.chance(0.05) {
+                *cell = b'.';
+            }
+        }
+    }
+
+    grid[side - 2][1] = b'S';
+    grid[1][side - 2] = b'E';
+    render(&grid)
+}
+
+/// Returns `true` if some value of register A makes `program` output itself.
+/// The program outputs `(a % 8) ^ k1 ^ k2 ^ (a >> ((a % 8) ^ k1))` and shifts A by three bits per loop,
+/// so A can be searched backwards, three bits per output.
+fn has_quine(program: &[u64], k1: u64, k2: u64) -> bool {
+    let output = |a: u64| {
+        let b = (a % 8) ^ k1;
+        (b ^ k2 ^ (a >> b)) % 8
+    };
+
+    let mut candidates = vec![0];
+    for &expected in program.iter().rev() {
+        candidates = candidates
+            .iter()
+            .flat_map(|a| (0..8).map(move |bits| a * 8 + bits))
+            .filter(|&a| a != 0 && output(a) == expected)
+            .collect();
+    }
+    !candidates.is_empty()
+}
+
+/// A program in the shape of the real puzzle: it prints a function of the lowest three bits
+/// of register A, then shifts A and jumps back to the start until A is zero.
+/// The constants are chosen so that the program has a quine for part two.
+fn computer(rng: &mut Rng, digits: usize) -> String {
+    let min = 8u64.pow(digits as u32 - 1);
+    let register_a = min + rng.next_u64() % (8u64.pow(digits as u32) - min);
+
+    let program = loop {
+        let (k1, k2, ignored) = (rng.next_u64() % 8, rng.next_u64() % 8, rng.next_u64() % 8);
+        let program = [2, 4, 1, k1, 7, 5, 1, k2, 4, ignored, 5, 5, 0, 3, 3, 0];
+        if has_quine(&program, k1, k2) {
+            break program;
+        }
+    };
+
+    format!(
+        "Register A: {register_a}\nRegister B: 0\nRegister C: 0\n\nProgram: {}",
+        program.iter().join(",")
+    )
+}
+
+const MEMORY_SIDE: usize = 71;
+const BYTES_FALLEN: usize = 1024;
+
+/// Returns `true` if the exit can be reached after `bytes` have fallen.
+fn exit_reachable(bytes: &[(usize, usize)]) -> bool {
+    let mut grid = vec![vec![b'.'; MEMORY_SIDE]; MEMORY_SIDE];
+    for &(x, y) in bytes {
+        grid[y][x] = b'#';
+    }
+
+    let mut queue = VecDeque::from([(0, 0)]);
+    grid[0][0] = b'O';
+    while let Some((x, y)) = queue.pop_front() {
+        if (x, y) == (MEMORY_SIDE as i64 - 1, MEMORY_SIDE as i64 - 1) {
+            return true;
+        }
+        for (dx, dy) in NEIGHBOURS {
+            if get(&grid, (y + dy, x + dx)) == Some(b'.') {
+                grid[(y + dy) as usize][(x + dx) as usize] = b'O';
+                queue.push_back((x + dx, y + dy));
+            }
+        }
+    }
+    false
+}
+
+/// The exit stays reachable after the first kilobyte, the way part one expects.
+fn falling_bytes(rng: &mut Rng, bytes: usize) -> String {
+    let exit = (MEMORY_SIDE - 1, MEMORY_SIDE - 1);
+    let mut cells = (0..MEMORY_SIDE)
+        .cartesian_product(0..MEMORY_SIDE)
+        .filter(|&cell| cell != (0, 0) && cell != exit)
+        .collect_vec();
+
+    loop {
+        rng.shuffle(&mut cells);
+        if exit_reachable(&cells[..BYTES_FALLEN]) {
+            return cells[..bytes]
+                .iter()
+                .map(|(x, y)| format!("{x},{y}"))
+                .join("\n");
+        }
+    }
+}
+
+/// One color has no single-stripe towel, so some of the random designs are impossible.
+fn towels(rng: &mut Rng, designs: usize) -> String {
+    const COLORS: &[u8] = b"wubrg";
+    let stripes = |rng: &mut Rng, len: usize| {
+        (0..len)
+            .map(|_| *rng.choose(COLORS) as char)
+            .collect::<String>()
+    };
+
+    let missing = (*rng.choose(COLORS) as char).to_string();
+    let mut seen = HashSet::from([missing]);
+    let mut patterns = vec![];
+    while patterns.len() < 400 {
+        let len = rng.range(1..=8) as usize;
+        let pattern = stripes(rng, len);
+        if seen.insert(pattern.clone()) {
+            patterns.push(pattern);
+        }
+    }
+
+    let designs = (0..designs).map(|_| {
+        let len = rng.range(20..=60) as usize;
+        if rng.chance(0.6) {
+            let mut design = String::new();
+            while design.len() < len {
+                let pattern = rng.choose(&patterns);
+                design.push_str(pattern);
+            }
+            design
+        } else {
+            stripes(rng, len)
+        }
+    });
+
+    format!("{}\n\n{}", patterns.join(", "), designs.format("\n"))
+}
+
+/// A single track without branches: the longest path from the corner of a perfect maze.
+/// The side is rounded up to be odd.
+fn race_track(rng: &mut Rng, side: usize) -> String {
+    let side = side | 1;
+    let grid = maze(rng, side);
+
+    let mut parents = vec![vec![None; side]; side];
+    let mut queue = VecDeque::from([(1, 1)]);
+    let mut end = (1, 1);
+    parents[1][1] = Some((1, 1));
+    while let Some((r, c)) = queue.pop_front() {
+        end = (r, c);
+        for (dr, dc) in NEIGHBOURS {
+            let next = (r + dr, c + dc);
+            if get(&grid, next) == Some(b'.') && parents[next.0 as usize][next.1 as usize].is_none()
+            {
+                parents[next.0 as usize][next.1 as usize] = Some((r, c));
+                queue.push_back(next);
+            }
+        }
+    }
+
+    let mut track = vec![vec![b'#'; side]; side];
+    let mut pos = end;
+    while pos != (1, 1) {
+        track[pos.0 as usize][pos.1 as usize] = b'.';
+        pos = parents[pos.0 as usize][pos.1 as usize].unwrap();
+    }
+    track[1][1] = b'S';
+    track[end.0 as usize][end.1 as usize] = b'E';
+    render(&track)
+}
+
+fn door_codes(rng: &mut Rng, codes: usize) -> String {
+    let mut numbers = (0..1000).collect_vec();
+    rng.shuffle(&mut numbers);
+    numbers[..codes]
+        .iter()
+        .map(|n| format!("{n:03}A"))
+        .join("\n")
+}
+
+fn secrets(rng: &mut Rng, buyers: usize) -> String {
+    (0..buyers).map(|_| rng.range(1..=16_777_215)).join("\n")
+}
+
+/// Computers have about 12 random connections, the first 13 of them are all connected to each other.
+fn lan_party(rng: &mut Rng, computers: usize) -> String {
+    let mut names = (b'a'..=b'z')
+        .cartesian_product(b'a'..=b'z')
+        .map(|(a, b)| format!("{}{}", a as char, b as char))
+        .collect_vec();
+    rng.shuffle(&mut names);
+    names.truncate(computers);
+
+    let p = 12.0 / computers as f64;
+    let mut connections = (0..computers)
+        .tuple_combinations()
+        .filter_map(|(a, b)| {
+            let connected = (a < 13 && b < 13) || rng.chance(p);
+            let (a, b) = if rng.chance(0.5) { (a, b) } else { (b, a) };
+            connected.then(|| format!("{}-{}", names[a], names[b]))
+        })
+        .collect_vec();
+    rng.shuffle(&mut connections);
+    connections.join("\n")
+}
+
+/// A ripple-carry adder with the outputs of four gate pairs swapped, each pair within a single bit.
+fn adder(rng: &mut Rng, bits: usize) -> String {
+    let mut used = HashSet::new();
+    let mut wire = |rng: &mut Rng| loop {
+        let name: String = (0..3)
+            .map(|_| (b'a' + rng.below(23) as u8) as char)
+            .collect();
+        if used.insert(name.clone()) {
+            break name;
+        }
+    };
+
+    let mut gates: Vec<(String, &str, String, String)> = vec![];
+    let mut swappable = vec![];
+    let mut carry = wire(rng);
+    gates.push(("x00".into(), "XOR", "y00".into(), "z00".into()));
+    gates.push(("x00".into(), "AND", "y00".into(), carry.clone()));
+
+    for i in 1..bits {
+        let (x, y, z) = (format!("x{i:02}"), format!("y{i:02}"), format!("z{i:02}"));
+        let (half, generate, propagate) = (wire(rng), wire(rng), wire(rng));
+        let next_carry = if i == bits - 1 {
+            format!("z{bits:02}")
+        } else {
+            wire(rng)
+        };
+
+        let first = gates.len();
+        gates.push((x.clone(), "XOR", y.clone(), half.clone()));
+        gates.push((x, "AND", y, generate.clone()));
+        gates.push((half.clone(), "XOR", carry.clone(), z));
+        gates.push((half, "AND", carry, propagate.clone()));
+        gates.push((generate, "OR", propagate, next_carry.clone()));
+        carry = next_carry;
+
+        // swapping outputs within a bit never creates a cycle.
+        if i < bits - 1 {
+            swappable.push(match rng.below(4) {
+                0 => (first, first + 1),
+                1 => (first + 2, first + 1),
+                2 => (first + 2, first + 3),
+                _ => (first + 2, first + 4),
+            });
+        }
+    }
+
+    rng.shuffle(&mut swappable);
+    for &(a, b) in &swappable[..4] {
+        let output = gates[a].3.clone();
+        gates[a].3 = std::mem::replace(&mut gates[b].3, output);
+    }
+
+    let inputs = ["x", "y"]
+        .iter()
+        .flat_map(|register| (0..bits).map(move |i| format!("{register}{i:02}")))
+        .map(|name| format!("{name}: {}", rng.below(2)))
+        .collect_vec();
+
+    rng.shuffle(&mut gates);
+    let gates = gates.into_iter().map(|(a, op, b, out)| {
+        let (a, b) = if rng.chance(0.5) { (a, b) } else { (b, a) };
+        format!("{a} {op} {b} -> {out}")
+    });
+
+    format!("{}\n\n{}", inputs.join("\n"), gates.format("\n"))
+}
+
+fn schematics(rng: &mut Rng, schematics: usize) -> String {
+    (0..schematics)
+        .map(|_| {
+            let is_lock = rng.chance(0.5);
+            let heights = (0..5).map(|_| rng.below(6)).collect_vec();
+            (0..7)
+                .map(|row| {
+                    heights
+                        .iter()
+                        .map(|&h| {
+                            let filled = if is_lock { row <= h } else { 6 - row <= h };
+                            if filled {
+                                '#'
+                            } else {
+                                '.'
+                            }
+                        })
+                        .collect::<String>()
+                })
+                .join("\n")
+        })
+        .join("\n\n")
+}
+
+/* -------------------------------------------------------------------------- */
+
+#[cfg(feature = "test_lib")]
+mod tests {
+    use super::{generator_for, guard_steps, GENERATORS, NEIGHBOURS};
+    use crate::day;
+
+    #[test]
+    fn generates_reproducibly() {
+        for generator in GENERATORS {
+            let size = generator.min_size.max(7);
+            let input = generator.generate(size, 42);
+            assert_eq!(input, generator.generate(size, 42), "{}", generator.size);
+            assert_eq!(input.trim().is_empty(), false, "{}", generator.size);
+        }
+    }
+
+    #[test]
+    fn clamps_size() {
+        let generator = generator_for(day!(21));
+        assert_eq!(generator.generate(5000, 1).lines().count(), 1000);
+        assert_eq!(generator_for(day!(1)).generate(0, 1).lines().count(), 1);
+    }
+
+    #[test]
+    fn guard_leaves_map() {
+        for seed in 0..20 {
+            let input = generator_for(day!(6)).generate(20, seed);
+            let grid: Vec<Vec<u8>> = input.lines().map(|l| l.bytes().collect()).collect();
+            let start = input.replace('\n', "").find('^').unwrap();
+            assert_eq!(
+                guard_steps(&grid, ((start / 20) as i64, (start % 20) as i64)).is_some(),
+                true
+            );
+        }
+    }
+
+    #[test]
+    fn race_track_has_no_branches() {
+        let input = generator_for(day!(20)).generate(31, 3);
+        let grid: Vec<Vec<u8>> = input.lines().map(|l| l.bytes().collect()).collect();
+        for (r, row) in grid.iter().enumerate() {
+            for (c, &cell) in row.iter().enumerate() {
+                if cell != b'#' {
+                    let open = NEIGHBOURS
+                        .iter()
+                        .filter(|(dr, dc)| {
+                            let (r, c) = (r as i64 + dr, c as i64 + dc);
+                            grid[r as usize][c as usize] != b'#'
+                        })
+                        .count();
+                    assert_eq!(open <= 2, true);
+                }
+            }
+        }
+    }
+}
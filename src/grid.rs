@@ -0,0 +1,319 @@
+//! A rectangular grid of cells, stored flat in row-major order.
+//!
+//! Positions are `(row, column)` pairs. Positions outside of the grid are never an error,
+//! [`Grid::get`] returns `None` for them, so that a `wrapping_sub` past the first row or column is
+//! simply out of bounds.
+use std::fmt::Display;
+use std::ops::{Index, IndexMut};
+use std::str::FromStr;
+
+use crate::parse::{grid_of, ParseError};
+use crate::point::{Dir4, Dir8, Point};
+
+/// A position in a [`Grid`], as `(row, column)`.
+pub type Pos = (usize, usize);
+
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct Grid<T> {
+    width: usize,
+    height: usize,
+    cells: Vec<T>,
+}
+
+impl<T> Grid<T> {
+    /// Creates a grid from its cells in row-major order.
+    ///
+    /// # Panics
+    /// If the number of cells does not match the dimensions.
+    pub fn new(width: usize, height: usize, cells: Vec<T>) -> Self {
+        assert_eq!(
+            cells.len(),
+            width * height,
+            "a {width}x{height} grid needs {} cells",
+            width * height
+        );
+        Self {
+            width,
+            height,
+            cells,
+        }
+    }
+
+    /// Creates a grid with every cell set to `value`.
+    pub fn filled(width: usize, height: usize, value: T) -> Self
+    where
+        T: Clone,
+    {
+        Self::new(width, height, vec![value; width * height])
+    }
+
+    /// Creates a grid from its rows.
+    ///
+    /// # Panics
+    /// If the rows differ in length.
+    pub fn from_rows(rows: Vec<Vec<T>>) -> Self {
+        let height = rows.len();
+        let width = rows.first().map_or(0, Vec::len);
+        assert!(
+            rows.iter().all(|row| row.len() == width),
+            "rows of a grid must have the same length"
+        );
+        Self::new(width, height, rows.into_iter().flatten().collect())
+    }
+
+    /// Parses a rectangular grid of characters, mapping each cell with `f`.
+    /// See [`grid_of`] for the errors.
+    pub fn parse(
+        input: &str,
+        expected: &str,
+        f: impl FnMut(char) -> Option<T>,
+    ) -> Result<Self, ParseError> {
+        grid_of(input, expected, f).map(Self::from_rows)
+    }
+
+    pub fn width(&self) -> usize {
+        self.width
+    }
+
+    pub fn height(&self) -> usize {
+        self.height
+    }
+
+    pub fn contains(&self, (row, column): Pos) -> bool {
+        row < self.height && column < self.width
+    }
+
+    pub fn get(&self, pos: Pos) -> Option<&T> {
+        self.contains(pos)
+            .then(|| &self.cells[pos.0 * self.width + pos.1])
+    }
+
+    pub fn get_mut(&mut self, pos: Pos) -> Option<&mut T> {
+        if self.contains(pos) {
+            Some(&mut self.cells[pos.0 * self.width + pos.1])
+        } else {
+            None
+        }
+    }
+
+    /// Returns all positions in row-major order.
+    pub fn positions(&self) -> impl Iterator<Item = Pos> {
+        let width = self.width;
+        (0..self.cells.len()).map(move |i| (i / width, i % width))
+    }
+
+    /// Returns all cells with their positions in row-major order.
+    pub fn iter(&self) -> impl Iterator<Item = (Pos, &T)> {
+        self.positions().zip(&self.cells)
+    }
+
+    /// Returns the neighbor of `pos` in direction `dir`, if it is inside the grid.
+    pub fn step(&self, pos: Pos, dir: impl Into<Point>) -> Option<Pos> {
+        // `Point::from` would panic on positions past `isize::MAX`, e.g. from a `wrapping_sub`.
+        let offset = dir.into();
+        let row = isize::try_from(pos.0).ok()?.checked_add(offset.row)?;
+        let column = isize::try_from(pos.1).ok()?.checked_add(offset.column)?;
+        Point::new(row, column).to_pos(self.width, self.height)
+    }
+
+    /// Returns the orthogonal neighbors of `pos` that are inside the grid, clockwise from the top.
+    pub fn neighbors4(&self, pos: Pos) -> impl Iterator<Item = Pos> + '_ {
+        Dir4::ALL
+            .into_iter()
+            .filter_map(move |dir| self.step(pos, dir))
+    }
+
+    /// Returns all neighbors of `pos` that are inside the grid, including diagonal ones, clockwise from the top.
+    pub fn neighbors8(&self, pos: Pos) -> impl Iterator<Item = Pos> + '_ {
+        Dir8::ALL
+            .into_iter()
+            .filter_map(move |dir| self.step(pos, dir))
+    }
+
+    pub fn row(&self, row: usize) -> &[T] {
+        &self.cells[row * self.width..(row + 1) * self.width]
+    }
+
+    pub fn rows(&self) -> impl Iterator<Item = &[T]> {
+        // `chunks` of an empty grid with a width of 0 would panic.
+        self.cells.chunks(self.width.max(1))
+    }
+
+    pub fn column(&self, column: usize) -> impl Iterator<Item = &T> {
+        assert!(column < self.width, "column {column} is out of bounds");
+        self.cells.iter().skip(column).step_by(self.width)
+    }
+
+    /// Returns the position of the first cell equal to `value`, in row-major order.
+    pub fn find(&self, value: &T) -> Option<Pos>
+    where
+        T: PartialEq,
+    {
+        self.find_all(value).next()
+    }
+
+    /// Returns the positions of all cells equal to `value`, in row-major order.
+    pub fn find_all<'a>(&'a self, value: &'a T) -> impl Iterator<Item = Pos> + 'a
+    where
+        T: PartialEq,
+    {
+        self.iter()
+            .filter(move |(_, cell)| *cell == value)
+            .map(|(pos, _)| pos)
+    }
+
+    /// Maps every cell with `f`, keeping the dimensions.
+    pub fn map<U>(&self, f: impl FnMut(&T) -> U) -> Grid<U> {
+        Grid::new(self.width, self.height, self.cells.iter().map(f).collect())
+    }
+
+    /// Mirrors the grid along its main diagonal, rows become columns.
+    #[must_use]
+    pub fn transpose(&self) -> Self
+    where
+        T: Clone,
+    {
+        let cells = (0..self.width)
+            .flat_map(|column| self.column(column).cloned())
+            .collect();
+        Grid::new(self.height, self.width, cells)
+    }
+
+    /// Rotates the grid by 90 degrees clockwise.
+    #[must_use]
+    pub fn rotate_right(&self) -> Self
+    where
+        T: Clone,
+    {
+        let cells = (0..self.width)
+            .flat_map(|column| {
+                (0..self.height)
+                    .rev()
+                    .map(move |row| self[(row, column)].clone())
+            })
+            .collect();
+        Grid::new(self.height, self.width, cells)
+    }
+
+    /// Rotates the grid by 90 degrees counterclockwise.
+    #[must_use]
+    pub fn rotate_left(&self) -> Self
+    where
+        T: Clone,
+    {
+        let cells = (0..self.width)
+            .rev()
+            .flat_map(|column| self.column(column).cloned())
+            .collect();
+        Grid::new(self.height, self.width, cells)
+    }
+}
+
+impl<T> Index<Pos> for Grid<T> {
+    type Output = T;
+
+    fn index(&self, pos: Pos) -> &T {
+        self.get(pos).unwrap_or_else(|| {
+            panic!(
+                "position {pos:?} is outside of the {}x{} grid",
+                self.width, self.height
+            )
+        })
+    }
+}
+
+impl<T> IndexMut<Pos> for Grid<T> {
+    fn index_mut(&mut self, pos: Pos) -> &mut T {
+        let (width, height) = (self.width, self.height);
+        self.get_mut(pos)
+            .unwrap_or_else(|| panic!("position {pos:?} is outside of the {width}x{height} grid"))
+    }
+}
+
+/// Parses a grid of arbitrary characters.
+impl FromStr for Grid<char> {
+    type Err = ParseError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        Self::parse(s, "a character", Some)
+    }
+}
+
+/// Renders the grid as text, one line per row.
+impl<T: Display> Display for Grid<T> {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        for (i, row) in self.rows().enumerate() {
+            if i > 0 {
+                writeln!(f)?;
+            }
+            for cell in row {
+                write!(f, "{cell}")?;
+            }
+        }
+        Ok(())
+    }
+}
+
+/* -------------------------------------------------------------------------- */
+
+#[cfg(feature = "test_lib")]
+mod tests {
+    use super::Grid;
+    use crate::point::{Dir4, Dir8};
+
+    fn grid() -> Grid<char> {
+        "abc\ndef\n".parse().unwrap()
+    }
+
+    #[test]
+    fn parses_and_displays() {
+        let grid = grid();
+        assert_eq!((grid.width(), grid.height()), (3, 2));
+        assert_eq!(grid[(1, 2)], 'f');
+        assert_eq!(grid.to_string(), "abc\ndef");
+
+        let digits = Grid::parse("12\n3x\n", "a digit", |c| c.to_digit(10)).unwrap_err();
+        assert_eq!((digits.line, digits.column), (2, 2));
+    }
+
+    #[test]
+    fn checks_bounds() {
+        let grid = grid();
+        assert_eq!(grid.get((0, 0)), Some(&'a'));
+        assert_eq!(grid.get((2, 0)), None);
+        assert_eq!(grid.get((0, 0usize.wrapping_sub(1))), None);
+    }
+
+    #[test]
+    fn iterates_neighbors() {
+        let grid = grid();
+        let neighbors: Vec<_> = grid.neighbors4((0, 1)).collect();
+        assert_eq!(neighbors, vec![(0, 2), (1, 1), (0, 0)]);
+        assert_eq!(grid.neighbors8((0, 0)).count(), 3);
+        assert_eq!(grid.neighbors8((1, 1)).count(), 5);
+        assert_eq!(grid.step((1, 0), Dir4::North), Some((0, 0)));
+        assert_eq!(grid.step((1, 0), Dir8::SouthWest), None);
+        assert_eq!(grid.step((0, 0_usize.wrapping_sub(1)), Dir4::East), None);
+        assert_eq!(grid.neighbors8((usize::MAX, 1)).count(), 0);
+    }
+
+    #[test]
+    fn views_rows_and_columns() {
+        let grid = grid();
+        assert_eq!(grid.row(1), ['d', 'e', 'f']);
+        assert_eq!(grid.column(1).collect::<String>(), "be");
+        assert_eq!(grid.rows().count(), 2);
+        assert_eq!(grid.find(&'e'), Some((1, 1)));
+        assert_eq!(grid.find(&'z'), None);
+    }
+
+    #[test]
+    fn transforms() {
+        let grid = grid();
+        assert_eq!(grid.transpose().to_string(), "ad\nbe\ncf");
+        assert_eq!(grid.rotate_right().to_string(), "da\neb\nfc");
+        assert_eq!(grid.rotate_left().to_string(), "cf\nbe\nad");
+        assert_eq!(grid.rotate_right().rotate_left(), grid);
+        assert_eq!(grid.map(|c| c.to_ascii_uppercase()).to_string(), "ABC\nDEF");
+    }
+}
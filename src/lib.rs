@@ -1,3 +1,24 @@
+// lets the day modules refer to the library by name, as their binaries do.
+extern crate self as advent_of_code;
+
+pub mod bitset;
+pub mod cycle;
+pub mod days;
+pub mod fuzz;
+pub mod generate;
+pub mod grid;
+pub mod math;
+pub mod memo;
+pub mod parse;
+pub mod point;
+pub mod render;
+pub mod search;
 pub mod template;
+pub mod union_find;
+
+// defined here rather than by `solution!`, which also expands inside the library.
+#[cfg(feature = "dhat-heap")]
+#[global_allocator]
+static ALLOC: dhat::Alloc = dhat::Alloc;
 
 // Use this file to add helper functions and additional modules.
@@ -1,18 +1,26 @@
-use advent_of_code::template::commands::{all, download, read, scaffold, solve, time};
+use advent_of_code::template::commands::{
+    all, download, fuzz, gen_input, read, scaffold, solve, status, test, time, titles,
+    wait_and_fetch, watch,
+};
+use advent_of_code::template::DaySet;
 use args::{parse, AppArguments};
 
+#[cfg(feature = "tui")]
+use advent_of_code::template::commands::dashboard;
+
 #[cfg(feature = "today")]
-use advent_of_code::template::Day;
+use advent_of_code::template::{unlock, Day};
 #[cfg(feature = "today")]
 use std::process;
 
 mod args {
-    use advent_of_code::template::Day;
+    use advent_of_code::template::commands::scaffold;
+    use advent_of_code::template::{Day, DaySet};
     use std::process;
 
     pub enum AppArguments {
         Download {
-            day: Day,
+            days: DaySet,
         },
         Read {
             day: Day,
@@ -21,61 +29,140 @@ mod args {
             day: Day,
             download: bool,
             overwrite: bool,
+            template: Option<String>,
+            example_answers: [Option<u64>; 2],
         },
         Solve {
-            day: Day,
+            days: DaySet,
             release: bool,
             dhat: bool,
             submit: Option<u8>,
         },
         All {
+            days: Option<DaySet>,
             release: bool,
         },
         Time {
             all: bool,
-            day: Option<Day>,
+            days: Option<DaySet>,
             store: bool,
+            memory: bool,
+            scale: Option<u32>,
+            truncate: bool,
         },
+        Test {
+            days: Option<DaySet>,
+        },
+        Titles,
+        Status,
+        WatchDay {
+            day: Day,
+        },
+        FuzzDay {
+            day: Day,
+            iterations: Option<u64>,
+            seed: Option<u64>,
+        },
+        GenInput {
+            day: Day,
+            size: Option<usize>,
+            seed: Option<u64>,
+        },
+        WaitAndFetch,
         #[cfg(feature = "today")]
         Today,
+        #[cfg(feature = "tui")]
+        Dashboard,
     }
 
     pub fn parse() -> Result<AppArguments, Box<dyn std::error::Error>> {
         let mut args = pico_args::Arguments::from_env();
 
         let app_args = match args.subcommand()?.as_deref() {
-            Some("all") => AppArguments::All {
-                release: args.contains("--release"),
-            },
+            Some("all") => {
+                let release = args.contains("--release");
+
+                AppArguments::All {
+                    days: args.opt_free_from_str()?,
+                    release,
+                }
+            }
             Some("time") => {
                 let all = args.contains("--all");
                 let store = args.contains("--store");
+                let memory = args.contains("--memory");
+                let scale = args.opt_value_from_str("--scale")?;
+                let truncate = args.contains("--truncate");
 
                 AppArguments::Time {
                     all,
-                    day: args.opt_free_from_str()?,
+                    days: args.opt_free_from_str()?,
                     store,
+                    memory,
+                    scale,
+                    truncate,
                 }
             }
-            Some("download") => AppArguments::Download {
-                day: args.free_from_str()?,
+            Some("test") => AppArguments::Test {
+                days: args.opt_free_from_str()?,
             },
-            Some("read") => AppArguments::Read {
+            Some("titles") => AppArguments::Titles,
+            Some("status") => AppArguments::Status,
+            Some("watch-day") => AppArguments::WatchDay {
                 day: args.free_from_str()?,
             },
-            Some("scaffold") => AppArguments::Scaffold {
+            Some("fuzz-day") => {
+                let iterations = args.opt_value_from_str("--iterations")?;
+                let seed = args.opt_value_from_str("--seed")?;
+
+                AppArguments::FuzzDay {
+                    day: args.free_from_str()?,
+                    iterations,
+                    seed,
+                }
+            }
+            Some("gen-input") => {
+                let size = args.opt_value_from_str("--size")?;
+                let seed = args.opt_value_from_str("--seed")?;
+
+                AppArguments::GenInput {
+                    day: args.free_from_str()?,
+                    size,
+                    seed,
+                }
+            }
+            Some("wait-and-fetch") => AppArguments::WaitAndFetch,
+            Some("download") => AppArguments::Download {
+                days: args.free_from_str()?,
+            },
+            Some("read") => AppArguments::Read {
                 day: args.free_from_str()?,
-                download: args.contains("--download"),
-                overwrite: args.contains("--overwrite"),
             },
+            Some("scaffold") => {
+                let template = args.opt_value_from_str("--template")?;
+                let example_answers = [
+                    args.opt_value_from_fn("--example-one", scaffold::parse_answer)?,
+                    args.opt_value_from_fn("--example-two", scaffold::parse_answer)?,
+                ];
+
+                AppArguments::Scaffold {
+                    day: args.free_from_str()?,
+                    download: args.contains("--download"),
+                    overwrite: args.contains("--overwrite"),
+                    template,
+                    example_answers,
+                }
+            }
             Some("solve") => AppArguments::Solve {
-                day: args.free_from_str()?,
+                days: args.free_from_str()?,
                 release: args.contains("--release"),
                 submit: args.opt_value_from_str("--submit")?,
                 dhat: args.contains("--dhat"),
             },
             #[cfg(feature = "today")]
             Some("today") => AppArguments::Today,
+            #[cfg(feature = "tui")]
+            Some("dashboard") => AppArguments::Dashboard,
             Some(x) => {
                 eprintln!("Unknown command: {x}");
                 process::exit(1);
@@ -102,32 +189,55 @@ fn main() {
             std::process::exit(1);
         }
         Ok(args) => match args {
-            AppArguments::All { release } => all::handle(release),
-            AppArguments::Time { day, all, store } => time::handle(day, all, store),
-            AppArguments::Download { day } => download::handle(day),
+            AppArguments::All { days, release } => {
+                all::handle(&days.unwrap_or_else(DaySet::all), release);
+            }
+            AppArguments::Time {
+                days,
+                all,
+                store,
+                memory,
+                scale,
+                truncate,
+            } => time::handle(days.as_ref(), all, store, memory, scale, truncate),
+            AppArguments::Test { days } => test::handle(&days.unwrap_or_else(DaySet::all)),
+            AppArguments::Titles => titles::handle(),
+            AppArguments::Status => status::handle(),
+            AppArguments::WatchDay { day } => watch::handle(day),
+            AppArguments::FuzzDay {
+                day,
+                iterations,
+                seed,
+            } => fuzz::handle(day, iterations, seed),
+            AppArguments::GenInput { day, size, seed } => gen_input::handle(day, size, seed),
+            AppArguments::WaitAndFetch => wait_and_fetch::handle(),
+            AppArguments::Download { days } => download::handle(&days),
             AppArguments::Read { day } => read::handle(day),
             AppArguments::Scaffold {
                 day,
                 download,
                 overwrite,
+                template,
+                example_answers,
             } => {
-                scaffold::handle(day, overwrite);
+                // download first, so the module is scaffolded with the puzzle's title
                 if download {
-                    download::handle(day);
+                    download::handle(&day.into());
                 }
+                scaffold::handle(day, overwrite, template.as_deref(), example_answers);
             }
             AppArguments::Solve {
-                day,
+                days,
                 release,
                 dhat,
                 submit,
-            } => solve::handle(day, release, dhat, submit),
+            } => solve::handle(&days, release, dhat, submit),
             #[cfg(feature = "today")]
             AppArguments::Today => {
                 match Day::today() {
                     Some(day) => {
-                        scaffold::handle(day, false);
-                        download::handle(day);
+                        download::handle(&day.into());
+                        scaffold::handle(day, false, None, [None, None]);
                         read::handle(day)
                     }
                     None => {
@@ -135,10 +245,15 @@ fn main() {
                             "`today` command can only be run between the 1st and \
                             the 25th of december. Please use `scaffold` with a specific day."
                         );
+                        if let Some(countdown) = unlock::countdown() {
+                            eprintln!("{countdown}, use `wait-and-fetch` to fetch it on unlock.");
+                        }
                         process::exit(1)
                     }
                 };
             }
+            #[cfg(feature = "tui")]
+            AppArguments::Dashboard => dashboard::handle(),
         },
     };
 }
@@ -0,0 +1,176 @@
+//! Exact arithmetic on small integer systems.
+//!
+//! Linear systems are solved without floating point: [`solve`] eliminates fraction-free (Bareiss)
+//! in `i128` and only divides at the end, so integer answers come out exact. Modular helpers
+//! work for any moduli, not only coprime ones.
+use num::rational::Ratio;
+use num::Zero;
+
+/// Returns `(g, x, y)` with `g = gcd(a, b) >= 0` and `a * x + b * y = g`.
+pub fn egcd(a: i64, b: i64) -> (i64, i64, i64) {
+    let (mut old_r, mut r) = (a, b);
+    let (mut old_x, mut x) = (1, 0);
+    let (mut old_y, mut y) = (0, 1);
+    while r != 0 {
+        let q = old_r / r;
+        (old_r, r) = (r, old_r - q * r);
+        (old_x, x) = (x, old_x - q * x);
+        (old_y, y) = (y, old_y - q * y);
+    }
+    if old_r < 0 {
+        (-old_r, -old_x, -old_y)
+    } else {
+        (old_r, old_x, old_y)
+    }
+}
+
+/// Returns the `x` in `0..m` with `a * x ≡ 1 (mod m)`, if `a` and `m` are coprime.
+pub fn mod_inverse(a: i64, m: i64) -> Option<i64> {
+    let (g, x, _) = egcd(a.rem_euclid(m), m);
+    (g == 1).then(|| x.rem_euclid(m))
+}
+
+/// Solves the congruences `x ≡ residue (mod modulus)`, given as `(residue, modulus)` pairs.
+///
+/// Returns the smallest non-negative solution and the modulus it repeats with (the lcm of all
+/// moduli), or `None` if the congruences contradict each other.
+///
+/// # Panics
+/// If a modulus is not positive.
+pub fn crt(congruences: impl IntoIterator<Item = (i64, i64)>) -> Option<(i64, i64)> {
+    congruences
+        .into_iter()
+        .try_fold((0, 1), |(r1, m1), (r2, m2)| {
+            assert!(m2 > 0, "modulus {m2} is not positive");
+            let (g, p, _) = egcd(m1, m2);
+            let diff = r2 - r1;
+            if diff % g != 0 {
+                return None;
+            }
+            let lcm = m1 / g * m2;
+            let k = (i128::from(diff / g) * i128::from(p)).rem_euclid(i128::from(m2 / g));
+            let x = (i128::from(r1) + i128::from(m1) * k).rem_euclid(i128::from(lcm));
+            Some((x as i64, lcm))
+        })
+}
+
+/* -------------------------------------------------------------------------- */
+
+/// Solves `a * [x, y] = b` for integers with Cramer's rule.
+/// Returns `None` if the system has no unique solution or it is not integral.
+pub fn solve_2x2([[a, b], [c, d]]: [[i64; 2]; 2], [e, f]: [i64; 2]) -> Option<[i64; 2]> {
+    let determinant = a * d - b * c;
+    if determinant == 0 {
+        return None;
+    }
+    let x = d * e - b * f;
+    let y = a * f - c * e;
+    (x % determinant == 0 && y % determinant == 0).then(|| [x / determinant, y / determinant])
+}
+
+/// Solves the square system `a * x = b` exactly.
+/// Returns `None` if `a` is singular.
+///
+/// # Panics
+/// If `a` is not an `n` x `n` matrix for the `n` entries of `b`.
+pub fn solve(a: &[Vec<i64>], b: &[i64]) -> Option<Vec<Ratio<i128>>> {
+    let n = b.len();
+    assert!(
+        a.len() == n && a.iter().all(|row| row.len() == n),
+        "expected a {n}x{n} matrix"
+    );
+    // augmented matrix [a | b]
+    let mut m: Vec<Vec<i128>> = a
+        .iter()
+        .zip(b)
+        .map(|(row, &b)| row.iter().chain([&b]).map(|&x| i128::from(x)).collect())
+        .collect();
+
+    // Bareiss: every division is exact, so the entries stay integers.
+    let mut previous = 1;
+    for k in 0..n {
+        let pivot = (k..n).find(|&i| m[i][k] != 0)?;
+        m.swap(k, pivot);
+        let (top, bottom) = m.split_at_mut(k + 1);
+        let pivot_row = &top[k];
+        for row in bottom {
+            for j in k + 1..=n {
+                row[j] = (row[j] * pivot_row[k] - row[k] * pivot_row[j]) / previous;
+            }
+            row[k] = 0;
+        }
+        previous = m[k][k];
+    }
+
+    let mut x = vec![Ratio::zero(); n];
+    for i in (0..n).rev() {
+        let rest = (i + 1..n).fold(Ratio::from_integer(m[i][n]), |acc, j| acc - x[j] * m[i][j]);
+        x[i] = rest / m[i][i];
+    }
+    Some(x)
+}
+
+/// Like [`solve`], but only accepts a solution in integers.
+pub fn solve_integer(a: &[Vec<i64>], b: &[i64]) -> Option<Vec<i64>> {
+    solve(a, b)?
+        .into_iter()
+        .map(|x| {
+            x.is_integer()
+                .then(|| i64::try_from(x.to_integer()).ok())
+                .flatten()
+        })
+        .collect()
+}
+
+/* -------------------------------------------------------------------------- */
+
+#[cfg(feature = "test_lib")]
+mod tests {
+    use num::rational::Ratio;
+
+    use super::{crt, egcd, mod_inverse, solve, solve_2x2, solve_integer};
+
+    #[test]
+    fn extended_gcd() {
+        for (a, b) in [(240, 46), (-12, 18), (7, 0), (0, -5)] {
+            let (g, x, y) = egcd(a, b);
+            assert_eq!(g, num::integer::gcd(a, b));
+            assert_eq!(a * x + b * y, g);
+        }
+        assert_eq!(mod_inverse(3, 7), Some(5));
+        assert_eq!(mod_inverse(-3, 7), Some(2));
+        assert_eq!(mod_inverse(4, 8), None);
+    }
+
+    #[test]
+    fn chinese_remainder() {
+        assert_eq!(crt([(2, 3), (3, 5), (2, 7)]), Some((23, 105)));
+        assert_eq!(crt([(1, 4), (3, 6)]), Some((9, 12)));
+        assert_eq!(crt([(1, 4), (2, 6)]), None);
+        assert_eq!(crt([]), Some((0, 1)));
+        // the period of day 14's robots
+        let (x, period) = crt([(99, 101), (56, 103)]).unwrap();
+        assert_eq!((x % 101, x % 103, period), (99, 56, 101 * 103));
+    }
+
+    #[test]
+    fn solves_systems() {
+        assert_eq!(
+            solve_2x2([[94, 22], [34, 67]], [8400, 5400]),
+            Some([80, 40])
+        );
+        assert_eq!(solve_2x2([[26, 67], [66, 21]], [12748, 12176]), None);
+        assert_eq!(solve_2x2([[1, 2], [2, 4]], [3, 6]), None);
+
+        let a = vec![vec![2, 1, -1], vec![-3, -1, 2], vec![-2, 1, 2]];
+        assert_eq!(solve_integer(&a, &[8, -11, -3]), Some(vec![2, 3, -1]));
+
+        let a = vec![vec![0, 2], vec![3, 0]];
+        assert_eq!(
+            solve(&a, &[1, 1]),
+            Some(vec![Ratio::new(1, 3), Ratio::new(1, 2)])
+        );
+        assert_eq!(solve_integer(&a, &[1, 1]), None);
+        assert_eq!(solve(&[vec![1, 2], vec![2, 4]], &[1, 2]), None);
+    }
+}
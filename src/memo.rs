@@ -0,0 +1,145 @@
+//! Memoization of recursive functions.
+//!
+//! A [`Memo`] owns the function it caches. The function receives the memo itself, so that it
+//! can recurse through [`Memo::get`] and every recursive call is cached, without threading a
+//! `HashMap` through the helpers by hand.
+use std::collections::HashMap;
+use std::fmt::{Debug, Display};
+use std::hash::Hash;
+use std::rc::Rc;
+
+type Function<'a, K, V> = Rc<dyn Fn(&mut Memo<'a, K, V>, &K) -> V + 'a>;
+
+pub struct Memo<'a, K, V> {
+    cache: HashMap<K, V>,
+    f: Function<'a, K, V>,
+    stats: Stats,
+}
+
+/// How often a [`Memo`] answered from its cache. Only counted in debug builds, where a memo also
+/// prints them when it is dropped if `AOC_MEMO_STATS` is set.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub struct Stats {
+    pub hits: u64,
+    pub misses: u64,
+}
+
+impl<'a, K: Eq + Hash + Clone, V: Clone> Memo<'a, K, V> {
+    /// Caches `f`, which recurses by calling `get` on the memo it receives.
+    pub fn new(f: impl Fn(&mut Self, &K) -> V + 'a) -> Self {
+        Self {
+            cache: HashMap::new(),
+            f: Rc::new(f),
+            stats: Stats::default(),
+        }
+    }
+
+    /// Returns the value of the function for `key`, computing it on the first call only.
+    pub fn get(&mut self, key: K) -> V {
+        if let Some(value) = self.cache.get(&key) {
+            if cfg!(debug_assertions) {
+                self.stats.hits += 1;
+            }
+            return value.clone();
+        }
+        if cfg!(debug_assertions) {
+            self.stats.misses += 1;
+        }
+        let f = Rc::clone(&self.f);
+        let value = f(self, &key);
+        self.cache.insert(key, value.clone());
+        value
+    }
+
+    /// Fixes the value for `key`, e.g. a base case of the recursion.
+    pub fn insert(&mut self, key: K, value: V) {
+        self.cache.insert(key, value);
+    }
+
+    /// Returns the number of cached values.
+    pub fn len(&self) -> usize {
+        self.cache.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.cache.is_empty()
+    }
+
+    pub fn stats(&self) -> Stats {
+        self.stats
+    }
+}
+
+impl<K, V> Debug for Memo<'_, K, V> {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("Memo")
+            .field("len", &self.cache.len())
+            .field("stats", &self.stats)
+            .finish()
+    }
+}
+
+impl<K, V> Drop for Memo<'_, K, V> {
+    fn drop(&mut self) {
+        if cfg!(debug_assertions) && std::env::var_os("AOC_MEMO_STATS").is_some() {
+            eprintln!("memo of {} values: {}", self.cache.len(), self.stats);
+        }
+    }
+}
+
+impl Display for Stats {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        let calls = self.hits + self.misses;
+        let rate = if calls == 0 {
+            0.0
+        } else {
+            100.0 * self.hits as f64 / calls as f64
+        };
+        write!(
+            f,
+            "{} hits, {} misses ({rate:.1}% hit rate)",
+            self.hits, self.misses
+        )
+    }
+}
+
+/* -------------------------------------------------------------------------- */
+
+#[cfg(feature = "test_lib")]
+mod tests {
+    use super::Memo;
+
+    #[test]
+    fn caches_recursive_calls() {
+        let mut paths = Memo::new(|memo, &(row, column): &(u32, u32)| {
+            if row == 0 || column == 0 {
+                1u64
+            } else {
+                memo.get((row - 1, column)) + memo.get((row, column - 1))
+            }
+        });
+        assert_eq!(paths.get((16, 16)), 601080390);
+        assert_eq!(paths.len(), 17 * 17 - 1);
+
+        if cfg!(debug_assertions) {
+            let stats = paths.stats();
+            assert_eq!(stats.misses, paths.len() as u64);
+            assert_eq!(stats.hits > 0, true);
+        }
+    }
+
+    #[test]
+    fn uses_inserted_values() {
+        let words = ["ab", "a", "b"];
+        let mut splits = Memo::new(|memo, text: &&str| {
+            words
+                .iter()
+                .filter_map(|word| text.strip_prefix(word))
+                .map(|rest| memo.get(rest))
+                .sum::<u64>()
+        });
+        splits.insert("", 1);
+        assert_eq!(splits.get("abab"), 4);
+        assert_eq!(splits.get("abc"), 0);
+    }
+}
@@ -0,0 +1,386 @@
+//! Small parsing combinators for puzzle inputs.
+//!
+//! Every helper returns a [`ParseError`] that points at the offending line and column,
+//! so malformed inputs fail with a readable message instead of an `unreachable!()`.
+use std::error::Error;
+use std::fmt::{Debug, Display};
+use std::marker::PhantomData;
+use std::str::FromStr;
+
+/// An error which can be returned when parsing a puzzle input.
+///
+/// Lines and columns are 1-based. Errors created inside a combinator closure are
+/// relative to the slice the closure received and are moved to the right line by the combinator.
+///
+/// # Display
+/// The error renders the offending line with a caret under the column:
+///
+/// ```text
+/// line 2, column 3: expected one of `.#`, found `x`
+///   |
+/// 2 | ..x.
+///   |   ^
+/// ```
+#[derive(Clone, PartialEq, Eq)]
+pub struct ParseError {
+    pub line: usize,
+    pub column: usize,
+    pub expected: String,
+    pub found: Option<String>,
+    text: String,
+}
+
+impl ParseError {
+    /// Creates a [`ParseError`] for the character at byte `offset` of `input`.
+    pub fn at(input: &str, offset: usize, expected: impl Into<String>) -> Self {
+        let offset = offset.min(input.len());
+        let line_start = input[..offset].rfind('\n').map_or(0, |i| i + 1);
+        let line_end = input[offset..]
+            .find('\n')
+            .map_or(input.len(), |i| offset + i);
+        let text = input[line_start..line_end].trim_end_matches('\r');
+
+        Self {
+            line: input[..offset].matches('\n').count() + 1,
+            column: input[line_start..offset].chars().count() + 1,
+            expected: expected.into(),
+            found: input[offset..line_end].chars().next().map(String::from),
+            text: text.to_owned(),
+        }
+    }
+
+    /// Creates a [`ParseError`] that points past the end of `input`.
+    pub fn end_of(input: &str, expected: impl Into<String>) -> Self {
+        Self::at(input, input.trim_end_matches('\n').len(), expected)
+    }
+
+    /// Replaces the token reported as found at the error position.
+    #[must_use]
+    pub fn found(mut self, found: impl Display) -> Self {
+        self.found = Some(found.to_string());
+        self
+    }
+
+    /// Moves the error down by `lines`, e.g. when the failing slice started in the middle of the input.
+    #[must_use]
+    pub fn offset_lines(mut self, lines: usize) -> Self {
+        self.line += lines;
+        self
+    }
+}
+
+impl Display for ParseError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(
+            f,
+            "line {}, column {}: expected {}",
+            self.line, self.column, self.expected
+        )?;
+        match &self.found {
+            Some(found) => writeln!(f, ", found `{found}`")?,
+            None => writeln!(f, ", found end of line")?,
+        }
+
+        let gutter = " ".repeat(self.line.to_string().len());
+        writeln!(f, "{gutter} |")?;
+        writeln!(f, "{} | {}", self.line, self.text)?;
+        write!(f, "{gutter} | {}^", " ".repeat(self.column - 1))
+    }
+}
+
+// NOTE: `unwrap()` prints the `Debug` representation, so render the caret there as well.
+impl Debug for ParseError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        writeln!(f)?;
+        Display::fmt(self, f)
+    }
+}
+
+impl Error for ParseError {}
+
+/* -------------------------------------------------------------------------- */
+
+/// A block of lines separated from its neighbors by an empty line.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Section<'a> {
+    /// Number of input lines before this section.
+    pub line: usize,
+    pub text: &'a str,
+}
+
+impl<'a> Section<'a> {
+    /// Parses the section, reporting errors relative to the whole input.
+    pub fn parse<T>(
+        self,
+        f: impl FnOnce(&'a str) -> Result<T, ParseError>,
+    ) -> Result<T, ParseError> {
+        f(self.text).map_err(|e| e.offset_lines(self.line))
+    }
+}
+
+/// Splits `input` into the blocks separated by empty lines.
+pub fn sections(input: &str) -> Vec<Section<'_>> {
+    let mut result = vec![];
+    let mut start = None;
+
+    for (i, line) in input.lines().enumerate() {
+        let offset = line.as_ptr() as usize - input.as_ptr() as usize;
+        match (line.trim().is_empty(), start) {
+            (false, None) => start = Some((i, offset)),
+            (true, Some((first_line, first_offset))) => {
+                result.push(Section {
+                    line: first_line,
+                    text: input[first_offset..offset].trim_end(),
+                });
+                start = None;
+            }
+            _ => {}
+        }
+    }
+
+    if let Some((line, offset)) = start {
+        result.push(Section {
+            line,
+            text: input[offset..].trim_end(),
+        });
+    }
+    result
+}
+
+/// Parses every non-empty line of `input` with `f`.
+pub fn lines_of<'a, T>(
+    input: &'a str,
+    mut f: impl FnMut(&'a str) -> Result<T, ParseError>,
+) -> Result<Vec<T>, ParseError> {
+    input
+        .lines()
+        .enumerate()
+        .filter(|(_, line)| !line.trim().is_empty())
+        .map(|(i, line)| f(line).map_err(|e| e.offset_lines(i)))
+        .collect()
+}
+
+/// Parses a rectangular grid of characters, mapping each cell with `f`.
+///
+/// `expected` describes the accepted characters, e.g. ``"one of `.#^`"``.
+pub fn grid_of<T>(
+    input: &str,
+    expected: &str,
+    mut f: impl FnMut(char) -> Option<T>,
+) -> Result<Vec<Vec<T>>, ParseError> {
+    let mut width = None;
+
+    lines_of(input, |line| {
+        let line = line.trim_end();
+        let row = line
+            .char_indices()
+            .map(|(j, c)| f(c).ok_or_else(|| ParseError::at(line, j, expected)))
+            .collect::<Result<Vec<_>, _>>()?;
+
+        match *width.get_or_insert(row.len()) {
+            n if n < row.len() => {
+                let offset = line.char_indices().nth(n).map_or(line.len(), |(j, _)| j);
+                Err(ParseError::at(line, offset, format!("a row of width {n}")))
+            }
+            n if n > row.len() => Err(ParseError::end_of(line, format!("a row of width {n}"))),
+            _ => Ok(row),
+        }
+    })
+}
+
+/// Parses every number in `input`, separated by whitespace and/or commas.
+pub fn numbers<T: FromStr>(input: &str) -> Result<Vec<T>, ParseError> {
+    input
+        .split(|c: char| c.is_whitespace() || c == ',')
+        .filter(|token| !token.is_empty())
+        .map(|token| {
+            token.parse().map_err(|_| {
+                let offset = token.as_ptr() as usize - input.as_ptr() as usize;
+                ParseError::at(input, offset, "a number").found(token)
+            })
+        })
+        .collect()
+}
+
+/* -------------------------------------------------------------------------- */
+
+/// An integer type that [`ints_in`] can extract.
+pub trait Integer: Copy {
+    /// Whether a `-` right before the digits makes the number negative.
+    const SIGNED: bool;
+
+    /// Converts ASCII digits, without checking them.
+    fn from_digits(digits: &[u8], negative: bool) -> Self;
+}
+
+macro_rules! impl_integer {
+    ($signed:literal: $($t:ty),*) => {$(
+        impl Integer for $t {
+            const SIGNED: bool = $signed;
+
+            fn from_digits(digits: &[u8], negative: bool) -> Self {
+                digits.iter().fold(0, |acc: $t, digit| {
+                    let digit = <$t>::from(digit - b'0');
+                    if negative {
+                        acc * 10 - digit
+                    } else {
+                        acc * 10 + digit
+                    }
+                })
+            }
+        }
+    )*};
+}
+
+impl_integer!(true: i16, i32, i64, i128, isize);
+impl_integer!(false: u8, u16, u32, u64, u128, usize);
+
+/// Iterator over the integers in a text, see [`ints_in`].
+#[derive(Debug, Clone)]
+pub struct Ints<'a, T> {
+    rest: &'a [u8],
+    integer: PhantomData<T>,
+}
+
+impl<T: Integer> Iterator for Ints<'_, T> {
+    type Item = T;
+
+    fn next(&mut self) -> Option<T> {
+        let bytes = self.rest;
+        let start = bytes.iter().position(u8::is_ascii_digit)?;
+        let end = bytes[start..]
+            .iter()
+            .position(|b| !b.is_ascii_digit())
+            .map_or(bytes.len(), |len| start + len);
+        let negative = T::SIGNED && start > 0 && bytes[start - 1] == b'-';
+
+        self.rest = &bytes[end..];
+        Some(T::from_digits(&bytes[start..end], negative))
+    }
+}
+
+/// Returns every integer in `input` without allocating, skipping all other text,
+/// e.g. `Button A: X+94, Y-34` holds `94` and `-34`.
+///
+/// Unlike [`numbers`], nothing is validated: overflows wrap in release builds, and for signed types
+/// a range such as `1-3` reads as `1` and `-3`.
+pub fn ints_in<T: Integer>(input: &(impl AsRef<[u8]> + ?Sized)) -> Ints<'_, T> {
+    Ints {
+        rest: input.as_ref(),
+        integer: PhantomData,
+    }
+}
+
+/// Returns the signed integers of a line with exactly `N` of them, see [`ints_in`].
+pub fn ints<const N: usize>(line: &str) -> Option<[i64; N]> {
+    exactly(ints_in(line))
+}
+
+/// Returns the unsigned integers of a line with exactly `N` of them, see [`ints_in`].
+pub fn uints<const N: usize>(line: &str) -> Option<[u64; N]> {
+    exactly(ints_in(line))
+}
+
+fn exactly<T: Integer + Default, const N: usize>(mut ints: Ints<'_, T>) -> Option<[T; N]> {
+    let mut result = [T::default(); N];
+    for slot in &mut result {
+        *slot = ints.next()?;
+    }
+    ints.next().is_none().then_some(result)
+}
+
+/* -------------------------------------------------------------------------- */
+
+#[cfg(feature = "test_lib")]
+mod tests {
+    use super::{grid_of, ints, ints_in, lines_of, numbers, sections, uints, ParseError};
+
+    fn cell(c: char) -> Option<bool> {
+        match c {
+            '.' => Some(false),
+            '#' => Some(true),
+            _ => None,
+        }
+    }
+
+    #[test]
+    fn locates_offset() {
+        let err = ParseError::at("abc\ndef\nghi", 9, "a letter");
+        assert_eq!(err.line, 3);
+        assert_eq!(err.column, 2);
+        assert_eq!(err.found.as_deref(), Some("h"));
+    }
+
+    #[test]
+    fn renders_caret() {
+        let err = grid_of("..#\n.x.\n", "one of `.#`", cell).unwrap_err();
+        let expected = [
+            "line 2, column 2: expected one of `.#`, found `x`",
+            "  |",
+            "2 | .x.",
+            "  |  ^",
+        ]
+        .join("\n");
+        assert_eq!(err.to_string(), expected);
+    }
+
+    #[test]
+    fn parses_grid() {
+        let grid = grid_of("#.\n.#\n", "one of `.#`", cell).unwrap();
+        assert_eq!(grid, vec![vec![true, false], vec![false, true]]);
+    }
+
+    #[test]
+    fn rejects_ragged_grid() {
+        let err = grid_of("#..\n.#\n", "one of `.#`", cell).unwrap_err();
+        assert_eq!((err.line, err.column), (2, 3));
+        assert_eq!(err.expected, "a row of width 3");
+
+        let err = grid_of("#.\n.#.\n", "one of `.#`", cell).unwrap_err();
+        assert_eq!((err.line, err.column), (2, 3));
+    }
+
+    #[test]
+    fn offsets_lines_in_sections() {
+        let input = "1 2\n3 4\n\n\n#.\n.?\n";
+        let parts = sections(input);
+        assert_eq!(parts.len(), 2);
+        assert_eq!(parts[0].text, "1 2\n3 4");
+        assert_eq!(parts[1].line, 4);
+
+        let err = parts[1]
+            .parse(|s| grid_of(s, "one of `.#`", cell))
+            .unwrap_err();
+        assert_eq!((err.line, err.column), (6, 2));
+    }
+
+    #[test]
+    fn parses_numbers() {
+        assert_eq!(numbers::<i64>("1, -2 3\n4").unwrap(), vec![1, -2, 3, 4]);
+
+        let err = lines_of("1 2\n3 x4", numbers::<u64>).unwrap_err();
+        assert_eq!((err.line, err.column), (2, 3));
+        assert_eq!(err.found.as_deref(), Some("x4"));
+    }
+
+    #[test]
+    fn extracts_ints() {
+        let line = "Button A: X+94, Y-34";
+        assert_eq!(ints_in::<i64>(line).collect::<Vec<_>>(), vec![94, -34]);
+        assert_eq!(ints_in::<u64>(line).collect::<Vec<_>>(), vec![94, 34]);
+        assert_eq!(ints_in::<i32>(b"p=0,4 v=-3,-3\n-5").sum::<i32>(), -7);
+        assert_eq!(
+            ints_in::<i64>("-9223372036854775808").next(),
+            Some(i64::MIN)
+        );
+        assert_eq!(ints_in::<u8>("no numbers - here").next(), None);
+    }
+
+    #[test]
+    fn parses_fixed_arity() {
+        assert_eq!(ints::<3>("1,-2,3"), Some([1, -2, 3]));
+        assert_eq!(ints::<3>("1,-2"), None);
+        assert_eq!(ints::<2>("1,-2,3"), None);
+        assert_eq!(uints::<2>("Prize: X=8400, Y=5400"), Some([8400, 5400]));
+    }
+}
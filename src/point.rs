@@ -0,0 +1,295 @@
+//! Signed 2D points and the directions between them.
+//!
+//! Points use the same `(row, column)` orientation as [`Grid`](crate::grid::Grid): north is a
+//! smaller row, east a larger column. Stepping off a grid is checked with [`Point::to_pos`] or
+//! [`Grid::step`](crate::grid::Grid::step) instead of wrapping `usize` arithmetic.
+use std::ops::{Add, AddAssign, Mul, Neg, Sub, SubAssign};
+use std::str::FromStr;
+
+use crate::grid::Pos;
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, PartialOrd, Ord, Default)]
+pub struct Point {
+    pub row: isize,
+    pub column: isize,
+}
+
+impl Point {
+    pub const ORIGIN: Self = Self::new(0, 0);
+
+    pub const fn new(row: isize, column: isize) -> Self {
+        Self { row, column }
+    }
+
+    /// Returns the neighboring point in direction `dir`.
+    #[must_use]
+    pub fn step(self, dir: impl Into<Point>) -> Self {
+        self + dir.into()
+    }
+
+    pub fn manhattan(self, other: Self) -> usize {
+        self.row.abs_diff(other.row) + self.column.abs_diff(other.column)
+    }
+
+    /// Converts the point to a grid position, if it is inside a `width` x `height` grid.
+    pub fn to_pos(self, width: usize, height: usize) -> Option<Pos> {
+        let row = usize::try_from(self.row).ok().filter(|r| *r < height)?;
+        let column = usize::try_from(self.column).ok().filter(|c| *c < width)?;
+        Some((row, column))
+    }
+}
+
+impl From<Pos> for Point {
+    /// # Panics
+    /// If a coordinate does not fit into an `isize`.
+    fn from((row, column): Pos) -> Self {
+        Self::new(
+            isize::try_from(row).expect("row does not fit into a point"),
+            isize::try_from(column).expect("column does not fit into a point"),
+        )
+    }
+}
+
+impl Add for Point {
+    type Output = Self;
+
+    fn add(self, other: Self) -> Self {
+        Self::new(self.row + other.row, self.column + other.column)
+    }
+}
+
+impl AddAssign for Point {
+    fn add_assign(&mut self, other: Self) {
+        *self = *self + other;
+    }
+}
+
+impl Sub for Point {
+    type Output = Self;
+
+    fn sub(self, other: Self) -> Self {
+        Self::new(self.row - other.row, self.column - other.column)
+    }
+}
+
+impl SubAssign for Point {
+    fn sub_assign(&mut self, other: Self) {
+        *self = *self - other;
+    }
+}
+
+impl Neg for Point {
+    type Output = Self;
+
+    fn neg(self) -> Self {
+        Self::new(-self.row, -self.column)
+    }
+}
+
+impl Mul<isize> for Point {
+    type Output = Self;
+
+    fn mul(self, factor: isize) -> Self {
+        Self::new(self.row * factor, self.column * factor)
+    }
+}
+
+/* -------------------------------------------------------------------------- */
+
+/// One of the four orthogonal directions.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, PartialOrd, Ord)]
+pub enum Dir4 {
+    North,
+    East,
+    South,
+    West,
+}
+
+impl Dir4 {
+    /// All directions, clockwise from north. `Dir4::ALL[dir.index()] == dir`.
+    pub const ALL: [Self; 4] = [Self::North, Self::East, Self::South, Self::West];
+
+    pub fn index(self) -> usize {
+        self as usize
+    }
+
+    #[must_use]
+    pub fn turn_right(self) -> Self {
+        Self::ALL[(self.index() + 1) % 4]
+    }
+
+    #[must_use]
+    pub fn turn_left(self) -> Self {
+        Self::ALL[(self.index() + 3) % 4]
+    }
+
+    #[must_use]
+    pub fn opposite(self) -> Self {
+        Self::ALL[(self.index() + 2) % 4]
+    }
+
+    pub fn offset(self) -> Point {
+        match self {
+            Self::North => Point::new(-1, 0),
+            Self::East => Point::new(0, 1),
+            Self::South => Point::new(1, 0),
+            Self::West => Point::new(0, -1),
+        }
+    }
+
+    /// Parses an arrow (`^>v<`) or a compass letter (`NESW`).
+    pub fn from_char(c: char) -> Option<Self> {
+        match c {
+            '^' | 'N' => Some(Self::North),
+            '>' | 'E' => Some(Self::East),
+            'v' | 'S' => Some(Self::South),
+            '<' | 'W' => Some(Self::West),
+            _ => None,
+        }
+    }
+
+    /// Returns the arrow pointing in this direction.
+    pub fn to_arrow(self) -> char {
+        ['^', '>', 'v', '<'][self.index()]
+    }
+}
+
+impl From<Dir4> for Point {
+    fn from(dir: Dir4) -> Self {
+        dir.offset()
+    }
+}
+
+/* -------------------------------------------------------------------------- */
+
+/// One of the eight compass directions, including the diagonals.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, PartialOrd, Ord)]
+pub enum Dir8 {
+    North,
+    NorthEast,
+    East,
+    SouthEast,
+    South,
+    SouthWest,
+    West,
+    NorthWest,
+}
+
+impl Dir8 {
+    /// All directions, clockwise from north. `Dir8::ALL[dir.index()] == dir`.
+    pub const ALL: [Self; 8] = [
+        Self::North,
+        Self::NorthEast,
+        Self::East,
+        Self::SouthEast,
+        Self::South,
+        Self::SouthWest,
+        Self::West,
+        Self::NorthWest,
+    ];
+
+    pub fn index(self) -> usize {
+        self as usize
+    }
+
+    /// Turns by 45 degrees clockwise.
+    #[must_use]
+    pub fn turn_right(self) -> Self {
+        Self::ALL[(self.index() + 1) % 8]
+    }
+
+    /// Turns by 45 degrees counterclockwise.
+    #[must_use]
+    pub fn turn_left(self) -> Self {
+        Self::ALL[(self.index() + 7) % 8]
+    }
+
+    #[must_use]
+    pub fn opposite(self) -> Self {
+        Self::ALL[(self.index() + 4) % 8]
+    }
+
+    pub fn offset(self) -> Point {
+        match self {
+            Self::North => Point::new(-1, 0),
+            Self::NorthEast => Point::new(-1, 1),
+            Self::East => Point::new(0, 1),
+            Self::SouthEast => Point::new(1, 1),
+            Self::South => Point::new(1, 0),
+            Self::SouthWest => Point::new(1, -1),
+            Self::West => Point::new(0, -1),
+            Self::NorthWest => Point::new(-1, -1),
+        }
+    }
+}
+
+impl From<Dir4> for Dir8 {
+    fn from(dir: Dir4) -> Self {
+        Self::ALL[dir.index() * 2]
+    }
+}
+
+impl From<Dir8> for Point {
+    fn from(dir: Dir8) -> Self {
+        dir.offset()
+    }
+}
+
+/// Parses a compass direction such as `N` or `SW`.
+impl FromStr for Dir8 {
+    type Err = String;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        const NAMES: [&str; 8] = ["N", "NE", "E", "SE", "S", "SW", "W", "NW"];
+        NAMES
+            .iter()
+            .position(|name| *name == s)
+            .map(|i| Self::ALL[i])
+            .ok_or_else(|| format!("expected one of {}, found {s:?}", NAMES.join(", ")))
+    }
+}
+
+/* -------------------------------------------------------------------------- */
+
+#[cfg(feature = "test_lib")]
+mod tests {
+    use super::{Dir4, Dir8, Point};
+
+    #[test]
+    fn turns() {
+        for dir in Dir4::ALL {
+            assert_eq!(dir.turn_right().turn_left(), dir);
+            assert_eq!(dir.turn_right().turn_right(), dir.opposite());
+            assert_eq!(dir.offset() + dir.opposite().offset(), Point::ORIGIN);
+        }
+        for dir in Dir8::ALL {
+            assert_eq!(dir.turn_left().turn_right(), dir);
+            assert_eq!(-dir.offset(), dir.opposite().offset());
+        }
+        assert_eq!(Dir4::West.turn_right(), Dir4::North);
+        assert_eq!(Dir8::North.turn_left(), Dir8::NorthWest);
+        assert_eq!(Dir8::from(Dir4::South), Dir8::South);
+    }
+
+    #[test]
+    fn parses() {
+        assert_eq!(Dir4::from_char('^'), Some(Dir4::North));
+        assert_eq!(Dir4::from_char('W'), Some(Dir4::West));
+        assert_eq!(Dir4::from_char('x'), None);
+        assert_eq!(Dir4::from_char(Dir4::East.to_arrow()), Some(Dir4::East));
+        assert_eq!("SW".parse(), Ok(Dir8::SouthWest));
+        assert_eq!("X".parse::<Dir8>().is_err(), true);
+    }
+
+    #[test]
+    fn steps_within_bounds() {
+        let corner = Point::from((0, 0));
+        assert_eq!(corner.step(Dir4::North).to_pos(3, 2), None);
+        assert_eq!(corner.step(Dir8::SouthEast).to_pos(3, 2), Some((1, 1)));
+        assert_eq!(
+            corner.step(Dir4::South).step(Dir4::South).to_pos(3, 2),
+            None
+        );
+        assert_eq!((Dir4::East.offset() * 3).manhattan(Point::new(1, 0)), 4);
+    }
+}
@@ -0,0 +1,332 @@
+//! Pictures of grids for debugging, in the terminal or as image files.
+//!
+//! A [`Picture`] is built from a [`Grid`], nested rows or a set of points, and overlays paths and
+//! highlighted cells on top. It prints as plain text with `Display`, as colored text with
+//! [`Picture::to_ansi`], and saves as PPM or PNG with one square of `scale` pixels per cell.
+use std::fmt::Display;
+use std::path::Path;
+use std::{fs, io};
+
+use crate::grid::{Grid, Pos};
+use crate::point::{Dir4, Point};
+use crate::template::ANSI_RESET;
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct Rgb(pub u8, pub u8, pub u8);
+
+impl Rgb {
+    pub const BLACK: Self = Self(0, 0, 0);
+    pub const WHITE: Self = Self(255, 255, 255);
+    pub const RED: Self = Self(230, 60, 60);
+    pub const GREEN: Self = Self(60, 200, 90);
+    pub const BLUE: Self = Self(70, 120, 230);
+    pub const YELLOW: Self = Self(240, 200, 50);
+}
+
+/// A cell of a [`Picture`]: the character shown in text, and an optional color.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct Pixel {
+    pub glyph: char,
+    pub color: Option<Rgb>,
+}
+
+impl Pixel {
+    pub const EMPTY: Self = Self::plain('.');
+
+    pub const fn plain(glyph: char) -> Self {
+        Self { glyph, color: None }
+    }
+
+    pub const fn colored(glyph: char, color: Rgb) -> Self {
+        Self {
+            glyph,
+            color: Some(color),
+        }
+    }
+
+    /// The color in images: its own color if it has one, otherwise black for `.` and blanks,
+    /// and white for everything else.
+    fn image_color(self) -> Rgb {
+        match (self.color, self.glyph) {
+            (Some(color), _) => color,
+            (None, '.' | ' ') => Rgb::BLACK,
+            (None, _) => Rgb::WHITE,
+        }
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Picture {
+    pixels: Grid<Pixel>,
+}
+
+impl Picture {
+    /// Creates a picture of empty cells.
+    pub fn new(width: usize, height: usize) -> Self {
+        Self {
+            pixels: Grid::filled(width, height, Pixel::EMPTY),
+        }
+    }
+
+    pub fn from_grid<T>(grid: &Grid<T>, f: impl FnMut(&T) -> Pixel) -> Self {
+        Self {
+            pixels: grid.map(f),
+        }
+    }
+
+    /// Creates a picture from rows of equal length.
+    pub fn from_rows<T>(rows: &[Vec<T>], mut f: impl FnMut(&T) -> Pixel) -> Self {
+        Self {
+            pixels: Grid::from_rows(
+                rows.iter()
+                    .map(|row| row.iter().map(&mut f).collect())
+                    .collect(),
+            ),
+        }
+    }
+
+    /// Draws `pixel` at each of `points` on an empty picture. Points outside are skipped.
+    pub fn from_points(
+        width: usize,
+        height: usize,
+        points: impl IntoIterator<Item = Pos>,
+        pixel: Pixel,
+    ) -> Self {
+        let mut picture = Self::new(width, height);
+        picture.highlight_with(points, |_| pixel);
+        picture
+    }
+
+    pub fn width(&self) -> usize {
+        self.pixels.width()
+    }
+
+    pub fn height(&self) -> usize {
+        self.pixels.height()
+    }
+
+    /// Sets the cell at `pos`, if it is inside the picture.
+    pub fn set(&mut self, pos: Pos, pixel: Pixel) -> &mut Self {
+        if let Some(cell) = self.pixels.get_mut(pos) {
+            *cell = pixel;
+        }
+        self
+    }
+
+    /// Colors the cells at `positions`, keeping their glyphs.
+    pub fn highlight(&mut self, positions: impl IntoIterator<Item = Pos>, color: Rgb) -> &mut Self {
+        self.highlight_with(positions, |pixel| Pixel::colored(pixel.glyph, color))
+    }
+
+    fn highlight_with(
+        &mut self,
+        positions: impl IntoIterator<Item = Pos>,
+        mut f: impl FnMut(Pixel) -> Pixel,
+    ) -> &mut Self {
+        for pos in positions {
+            if let Some(cell) = self.pixels.get_mut(pos) {
+                *cell = f(*cell);
+            }
+        }
+        self
+    }
+
+    /// Draws a path of orthogonal steps with arrows in `color`. The last cell keeps its glyph.
+    pub fn path(&mut self, path: &[Pos], color: Rgb) -> &mut Self {
+        for window in path.windows(2) {
+            let step = Point::from(window[1]) - Point::from(window[0]);
+            let glyph = Dir4::ALL
+                .into_iter()
+                .find(|dir| dir.offset() == step)
+                .map_or('*', Dir4::to_arrow);
+            self.set(window[0], Pixel::colored(glyph, color));
+        }
+        self.highlight(path.last().copied(), color)
+    }
+
+    /// Renders the picture as text with 24-bit ANSI colors.
+    pub fn to_ansi(&self) -> String {
+        let mut result = String::new();
+        for row in self.pixels.rows() {
+            for pixel in row {
+                match pixel.color {
+                    Some(Rgb(r, g, b)) => {
+                        result += &format!("\x1b[38;2;{r};{g};{b}m{}{ANSI_RESET}", pixel.glyph);
+                    }
+                    None => result.push(pixel.glyph),
+                }
+            }
+            result.push('\n');
+        }
+        result
+    }
+
+    /// Returns the RGB bytes of the image, row by row.
+    fn image(&self, scale: usize) -> Vec<u8> {
+        let mut bytes = Vec::with_capacity(self.width() * self.height() * scale * scale * 3);
+        for row in self.pixels.rows() {
+            let line: Vec<u8> = row
+                .iter()
+                .flat_map(|pixel| {
+                    let Rgb(r, g, b) = pixel.image_color();
+                    [r, g, b].repeat(scale)
+                })
+                .collect();
+            for _ in 0..scale {
+                bytes.extend_from_slice(&line);
+            }
+        }
+        bytes
+    }
+
+    /// Encodes the picture as a binary PPM image with `scale` x `scale` pixels per cell.
+    pub fn to_ppm(&self, scale: usize) -> Vec<u8> {
+        let (width, height) = (self.width() * scale, self.height() * scale);
+        let mut bytes = format!("P6\n{width} {height}\n255\n").into_bytes();
+        bytes.extend(self.image(scale));
+        bytes
+    }
+
+    /// Encodes the picture as a PNG image with `scale` x `scale` pixels per cell.
+    pub fn to_png(&self, scale: usize) -> Vec<u8> {
+        let (width, height) = (self.width() * scale, self.height() * scale);
+        let mut header = vec![];
+        header.extend(
+            u32::try_from(width)
+                .expect("image is too wide")
+                .to_be_bytes(),
+        );
+        header.extend(
+            u32::try_from(height)
+                .expect("image is too high")
+                .to_be_bytes(),
+        );
+        // 8 bit RGB, default compression, filter and interlacing
+        header.extend([8, 2, 0, 0, 0]);
+
+        // every row starts with filter type 0
+        let image = self.image(scale);
+        let rows: Vec<u8> = image
+            .chunks(width * 3)
+            .flat_map(|row| [&[0], row].concat())
+            .collect();
+
+        let mut bytes = b"\x89PNG\r\n\x1a\n".to_vec();
+        png_chunk(&mut bytes, b"IHDR", &header);
+        png_chunk(&mut bytes, b"IDAT", &zlib_stored(&rows));
+        png_chunk(&mut bytes, b"IEND", &[]);
+        bytes
+    }
+
+    /// Saves the picture as a PNG or, for a `.ppm` extension, a PPM image.
+    pub fn save(&self, path: impl AsRef<Path>, scale: usize) -> io::Result<()> {
+        let path = path.as_ref();
+        let bytes = match path.extension().and_then(|ext| ext.to_str()) {
+            Some("ppm") => self.to_ppm(scale),
+            _ => self.to_png(scale),
+        };
+        fs::write(path, bytes)
+    }
+}
+
+/// Renders the glyphs without colors, one line per row.
+impl Display for Picture {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        Display::fmt(&self.pixels.map(|pixel| pixel.glyph), f)
+    }
+}
+
+/* -------------------------------------------------------------------------- */
+
+fn png_chunk(bytes: &mut Vec<u8>, kind: &[u8; 4], data: &[u8]) {
+    let len = u32::try_from(data.len()).expect("PNG chunk is too large");
+    bytes.extend(len.to_be_bytes());
+    let start = bytes.len();
+    bytes.extend(kind);
+    bytes.extend(data);
+    let crc = crc32(&bytes[start..]);
+    bytes.extend(crc.to_be_bytes());
+}
+
+fn crc32(bytes: &[u8]) -> u32 {
+    !bytes.iter().fold(!0, |crc, &byte| {
+        (0..8).fold(crc ^ u32::from(byte), |crc, _| {
+            if crc & 1 == 1 {
+                (crc >> 1) ^ 0xedb8_8320
+            } else {
+                crc >> 1
+            }
+        })
+    })
+}
+
+/// Wraps `data` in a zlib stream of uncompressed blocks, so no compression library is needed.
+fn zlib_stored(data: &[u8]) -> Vec<u8> {
+    let mut bytes = vec![0x78, 0x01];
+    let mut blocks = data.chunks(u16::MAX as usize).peekable();
+    if blocks.peek().is_none() {
+        bytes.extend([1, 0, 0, 0xff, 0xff]);
+    }
+    while let Some(block) = blocks.next() {
+        let last = u8::from(blocks.peek().is_none());
+        let len = block.len() as u16;
+        bytes.push(last);
+        bytes.extend(len.to_le_bytes());
+        bytes.extend((!len).to_le_bytes());
+        bytes.extend(block);
+    }
+
+    let (a, b) = data.iter().fold((1u32, 0u32), |(a, b), &byte| {
+        let a = (a + u32::from(byte)) % 65521;
+        (a, (b + a) % 65521)
+    });
+    bytes.extend(((b << 16) | a).to_be_bytes());
+    bytes
+}
+
+/* -------------------------------------------------------------------------- */
+
+#[cfg(feature = "test_lib")]
+mod tests {
+    use super::{crc32, Picture, Pixel, Rgb};
+    use crate::grid::Grid;
+
+    fn picture() -> Picture {
+        let grid: Grid<char> = "#..\n.#.\n".parse().unwrap();
+        Picture::from_grid(&grid, |&c| Pixel::plain(c))
+    }
+
+    #[test]
+    fn renders_text() {
+        let mut picture = picture();
+        assert_eq!(picture.to_string(), "#..\n.#.");
+
+        picture.path(&[(0, 1), (0, 2), (1, 2)], Rgb::RED);
+        assert_eq!(picture.to_string(), "#>v\n.#.");
+        picture.highlight([(1, 1)], Rgb::GREEN);
+        assert_eq!(
+            picture.to_ansi().lines().nth(1),
+            Some(".\x1b[38;2;60;200;90m#\x1b[0m\x1b[38;2;230;60;60m.\x1b[0m")
+        );
+
+        let points = Picture::from_points(2, 2, [(1, 0), (5, 5)], Pixel::plain('o'));
+        assert_eq!(points.to_string(), "..\no.");
+        let rows = Picture::from_rows(&[vec![1, 22]], |n| {
+            Pixel::plain(if *n > 9 { '+' } else { '-' })
+        });
+        assert_eq!(rows.to_string(), "-+");
+    }
+
+    #[test]
+    fn encodes_images() {
+        let ppm = picture().to_ppm(2);
+        assert_eq!(ppm.starts_with(b"P6\n6 4\n255\n"), true);
+        assert_eq!(ppm.len(), 11 + 6 * 4 * 3);
+        assert_eq!(ppm[11..14], [255, 255, 255]);
+
+        let png = picture().to_png(1);
+        assert_eq!(png.starts_with(b"\x89PNG\r\n\x1a\n\0\0\0\x0dIHDR"), true);
+        assert_eq!(png.ends_with(b"IEND\xae\x42\x60\x82"), true);
+        assert_eq!(crc32(b"123456789"), 0xcbf4_3926);
+    }
+}
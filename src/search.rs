@@ -0,0 +1,334 @@
+//! Graph searches over implicit graphs.
+//!
+//! A graph is given by a `successors` closure that returns the neighbors of a node, together with
+//! the cost of the edge for the weighted searches. Paths are rebuilt from predecessor links, so a
+//! node is stored once no matter how many paths lead through it.
+use std::cmp::Reverse;
+use std::collections::hash_map::Entry;
+use std::collections::{BinaryHeap, HashMap, HashSet, VecDeque};
+use std::hash::Hash;
+
+use num::Zero;
+
+/// Marks the start node in the predecessor links.
+const NONE: usize = usize::MAX;
+
+/// The nodes seen by a search, numbered in the order they were discovered.
+struct Nodes<N> {
+    nodes: Vec<N>,
+    index: HashMap<N, usize>,
+}
+
+impl<N: Eq + Hash + Clone> Nodes<N> {
+    fn new(start: N) -> Self {
+        Self {
+            nodes: vec![start.clone()],
+            index: HashMap::from([(start, 0)]),
+        }
+    }
+
+    /// Returns the number of `node` and whether it was seen for the first time.
+    fn insert(&mut self, node: N) -> (usize, bool) {
+        match self.index.entry(node) {
+            Entry::Occupied(entry) => (*entry.get(), false),
+            Entry::Vacant(entry) => {
+                self.nodes.push(entry.key().clone());
+                entry.insert(self.nodes.len() - 1);
+                (self.nodes.len() - 1, true)
+            }
+        }
+    }
+
+    /// Follows the `parents` links back from `end` and returns the path from the start.
+    fn path(&self, parents: impl Fn(usize) -> usize, end: usize) -> Vec<N> {
+        let mut path = vec![];
+        let mut current = end;
+        while current != NONE {
+            path.push(self.nodes[current].clone());
+            current = parents(current);
+        }
+        path.reverse();
+        path
+    }
+}
+
+/// Finds a path with the fewest edges from `start` to a node for which `success` is true.
+/// The path includes both `start` and the final node.
+pub fn bfs<N, I>(
+    start: N,
+    mut successors: impl FnMut(&N) -> I,
+    mut success: impl FnMut(&N) -> bool,
+) -> Option<Vec<N>>
+where
+    N: Eq + Hash + Clone,
+    I: IntoIterator<Item = N>,
+{
+    let mut nodes = Nodes::new(start);
+    let mut parents = vec![NONE];
+    let mut queue = VecDeque::from([0]);
+
+    while let Some(i) = queue.pop_front() {
+        if success(&nodes.nodes[i]) {
+            return Some(nodes.path(|j| parents[j], i));
+        }
+        for next in successors(&nodes.nodes[i]) {
+            let (j, new) = nodes.insert(next);
+            if new {
+                parents.push(i);
+                queue.push_back(j);
+            }
+        }
+    }
+    None
+}
+
+/// Finds a cheapest path from `start` to a node for which `success` is true, and its cost.
+/// Edge costs must not be negative.
+pub fn dijkstra<N, C, I>(
+    start: N,
+    successors: impl FnMut(&N) -> I,
+    success: impl FnMut(&N) -> bool,
+) -> Option<(Vec<N>, C)>
+where
+    N: Eq + Hash + Clone,
+    C: Zero + Ord + Copy,
+    I: IntoIterator<Item = (N, C)>,
+{
+    astar(start, successors, |_| C::zero(), success)
+}
+
+/// Like [`dijkstra`], guided by a `heuristic` that estimates the remaining cost from a node.
+///
+/// The heuristic must never overestimate, and must not decrease by more than the cost of an
+/// edge (e.g. the Manhattan distance on a grid with unit steps), or the path may not be cheapest.
+pub fn astar<N, C, I>(
+    start: N,
+    mut successors: impl FnMut(&N) -> I,
+    mut heuristic: impl FnMut(&N) -> C,
+    mut success: impl FnMut(&N) -> bool,
+) -> Option<(Vec<N>, C)>
+where
+    N: Eq + Hash + Clone,
+    C: Zero + Ord + Copy,
+    I: IntoIterator<Item = (N, C)>,
+{
+    let mut heap = BinaryHeap::from([Reverse((heuristic(&start), C::zero(), 0))]);
+    let mut nodes = Nodes::new(start);
+    // cheapest known cost and the predecessor it came from
+    let mut best = vec![(C::zero(), NONE)];
+
+    while let Some(Reverse((_, cost, i))) = heap.pop() {
+        if cost > best[i].0 {
+            continue;
+        }
+        if success(&nodes.nodes[i]) {
+            return Some((nodes.path(|j| best[j].1, i), cost));
+        }
+        for (next, step) in successors(&nodes.nodes[i]) {
+            let next_cost = cost + step;
+            let (j, new) = nodes.insert(next);
+            if new {
+                best.push((next_cost, i));
+            } else if next_cost < best[j].0 {
+                best[j] = (next_cost, i);
+            } else {
+                continue;
+            }
+            heap.push(Reverse((
+                next_cost + heuristic(&nodes.nodes[j]),
+                next_cost,
+                j,
+            )));
+        }
+    }
+    None
+}
+
+/* -------------------------------------------------------------------------- */
+
+/// The shortest paths from a start node to every node reachable from it.
+pub struct Paths<N, C> {
+    nodes: Nodes<N>,
+    costs: Vec<C>,
+    /// All predecessors on a shortest path, the first one is the one found first.
+    predecessors: Vec<Vec<usize>>,
+}
+
+impl<N: Eq + Hash + Clone, C: Copy> Paths<N, C> {
+    /// Returns the cost of a shortest path to `node`, if it is reachable.
+    pub fn distance(&self, node: &N) -> Option<C> {
+        self.nodes.index.get(node).map(|&i| self.costs[i])
+    }
+
+    /// Returns all reachable nodes with the costs of their shortest paths.
+    pub fn distances(&self) -> impl Iterator<Item = (&N, C)> {
+        self.nodes.nodes.iter().zip(self.costs.iter().copied())
+    }
+
+    /// Returns one shortest path to `node`, including the start and `node`.
+    pub fn path_to(&self, node: &N) -> Option<Vec<N>> {
+        let end = *self.nodes.index.get(node)?;
+        Some(self.nodes.path(
+            |i| self.predecessors[i].first().copied().unwrap_or(NONE),
+            end,
+        ))
+    }
+
+    /// Returns every node that lies on some shortest path to one of `ends`, including the ends.
+    pub fn on_shortest_paths(&self, ends: impl IntoIterator<Item = N>) -> HashSet<N> {
+        let mut stack: Vec<usize> = ends
+            .into_iter()
+            .filter_map(|end| self.nodes.index.get(&end).copied())
+            .collect();
+        let mut seen: HashSet<usize> = stack.iter().copied().collect();
+        while let Some(i) = stack.pop() {
+            for &j in &self.predecessors[i] {
+                if seen.insert(j) {
+                    stack.push(j);
+                }
+            }
+        }
+        seen.into_iter()
+            .map(|i| self.nodes.nodes[i].clone())
+            .collect()
+    }
+}
+
+/// Finds the shortest paths, by number of edges, from `start` to every reachable node.
+pub fn bfs_paths<N, I>(start: N, mut successors: impl FnMut(&N) -> I) -> Paths<N, usize>
+where
+    N: Eq + Hash + Clone,
+    I: IntoIterator<Item = N>,
+{
+    let mut nodes = Nodes::new(start);
+    let mut costs = vec![0];
+    let mut predecessors = vec![vec![]];
+    let mut queue = VecDeque::from([0]);
+
+    while let Some(i) = queue.pop_front() {
+        for next in successors(&nodes.nodes[i]) {
+            let (j, new) = nodes.insert(next);
+            if new {
+                costs.push(costs[i] + 1);
+                predecessors.push(vec![i]);
+                queue.push_back(j);
+            } else if costs[j] == costs[i] + 1 {
+                predecessors[j].push(i);
+            }
+        }
+    }
+    Paths {
+        nodes,
+        costs,
+        predecessors,
+    }
+}
+
+/// Finds the cheapest paths from `start` to every reachable node.
+/// Edge costs must not be negative.
+pub fn dijkstra_paths<N, C, I>(start: N, mut successors: impl FnMut(&N) -> I) -> Paths<N, C>
+where
+    N: Eq + Hash + Clone,
+    C: Zero + Ord + Copy,
+    I: IntoIterator<Item = (N, C)>,
+{
+    let mut nodes = Nodes::new(start);
+    let mut costs = vec![C::zero()];
+    let mut predecessors = vec![vec![]];
+    let mut heap = BinaryHeap::from([Reverse((C::zero(), 0))]);
+
+    while let Some(Reverse((cost, i))) = heap.pop() {
+        if cost > costs[i] {
+            continue;
+        }
+        for (next, step) in successors(&nodes.nodes[i]) {
+            let next_cost = cost + step;
+            let (j, new) = nodes.insert(next);
+            if new {
+                costs.push(next_cost);
+                predecessors.push(vec![i]);
+            } else if next_cost < costs[j] {
+                costs[j] = next_cost;
+                predecessors[j] = vec![i];
+            } else {
+                if next_cost == costs[j] && !predecessors[j].contains(&i) {
+                    predecessors[j].push(i);
+                }
+                continue;
+            }
+            heap.push(Reverse((next_cost, j)));
+        }
+    }
+    Paths {
+        nodes,
+        costs,
+        predecessors,
+    }
+}
+
+/* -------------------------------------------------------------------------- */
+
+#[cfg(feature = "test_lib")]
+mod tests {
+    use std::collections::HashSet;
+
+    use super::{astar, bfs, bfs_paths, dijkstra, dijkstra_paths};
+
+    /// An open 5x5 grid.
+    fn neighbors(&(row, column): &(i32, i32)) -> Vec<(i32, i32)> {
+        [(-1, 0), (1, 0), (0, -1), (0, 1)]
+            .into_iter()
+            .map(|(dr, dc)| (row + dr, column + dc))
+            .filter(|(r, c)| (0..5).contains(r) && (0..5).contains(c))
+            .collect()
+    }
+
+    /// A line 0 - 1 - 2 - 3 with a shortcut from 0 to 3 that is more expensive than the line.
+    fn weighted(&node: &u32) -> Vec<(u32, u32)> {
+        match node {
+            0 => vec![(1, 1), (3, 5)],
+            1 => vec![(2, 1)],
+            2 => vec![(3, 1)],
+            _ => vec![],
+        }
+    }
+
+    #[test]
+    fn bfs_finds_a_shortest_path() {
+        let path = bfs((0, 0), neighbors, |&node| node == (4, 4)).unwrap();
+        assert_eq!(path.len(), 9);
+        assert_eq!(path.first(), Some(&(0, 0)));
+        assert_eq!(path.last(), Some(&(4, 4)));
+        assert_eq!(bfs((0, 0), neighbors, |&node| node == (5, 5)), None);
+    }
+
+    #[test]
+    fn dijkstra_prefers_cheap_paths() {
+        assert_eq!(
+            dijkstra(0, weighted, |&node| node == 3),
+            Some((vec![0, 1, 2, 3], 3))
+        );
+        let manhattan = |&(r, c): &(i32, i32)| 8 - r - c;
+        let unit = |node: &(i32, i32)| neighbors(node).into_iter().map(|next| (next, 1));
+        let (path, cost) = astar((0, 0), unit, manhattan, |&node| node == (4, 4)).unwrap();
+        assert_eq!((path.len(), cost), (9, 8));
+    }
+
+    #[test]
+    fn tracks_all_shortest_paths() {
+        let paths = bfs_paths((0, 0), neighbors);
+        assert_eq!(paths.distance(&(2, 3)), Some(5));
+        assert_eq!(paths.distances().count(), 25);
+        assert_eq!(paths.path_to(&(1, 1)).unwrap().len(), 3);
+        // every node of the square lies on a shortest path from corner to corner
+        assert_eq!(paths.on_shortest_paths([(4, 4)]).len(), 25);
+        assert_eq!(
+            paths.on_shortest_paths([(0, 2)]),
+            HashSet::from([(0, 0), (0, 1), (0, 2)])
+        );
+
+        let paths = dijkstra_paths(0, weighted);
+        assert_eq!(paths.distance(&3), Some(3));
+        assert_eq!(paths.on_shortest_paths([3]), HashSet::from([0, 1, 2, 3]));
+    }
+}
@@ -1,5 +1,5 @@
-use crate::template::{all_days, run_multi::run_multi};
+use crate::template::{run_multi::run_multi, DaySet};
 
-pub fn handle(is_release: bool) {
-    run_multi(&all_days().collect(), is_release, false);
+pub fn handle(days: &DaySet, is_release: bool) {
+    run_multi(&days.resolve().into_iter().collect(), is_release, false);
 }
@@ -0,0 +1,407 @@
+use std::{fs, io, path::Path};
+
+use ratatui::{
+    crossterm::event::{self, Event, KeyCode, KeyEventKind},
+    layout::{Constraint, Layout, Rect},
+    style::{Style, Stylize},
+    text::Line,
+    widgets::{Block, Paragraph, Row, Table, TableState, Wrap},
+    DefaultTerminal, Frame,
+};
+
+use crate::template::run_multi::{child_commands, get_path_for_bin};
+use crate::template::timings::{format_bytes, Timings};
+use crate::template::{all_days, aoc_cli, is_solved, metadata, Day};
+
+/// Returns the example files of a day: `<day>.txt`, followed by the part files `<day>-<part>.txt`
+/// in the order of their parts.
+fn example_paths(day: Day) -> Vec<String> {
+    let prefix = format!("{day}-");
+    let mut parts: Vec<(u32, String)> = fs::read_dir("data/examples")
+        .map(|entries| {
+            entries
+                .filter_map(Result::ok)
+                .filter_map(|entry| {
+                    let name = entry.file_name().into_string().ok()?;
+                    let part = name
+                        .strip_prefix(&prefix)?
+                        .strip_suffix(".txt")?
+                        .parse()
+                        .ok()?;
+                    Some((part, format!("data/examples/{name}")))
+                })
+                .collect()
+        })
+        .unwrap_or_default();
+    parts.sort();
+
+    let whole = format!("data/examples/{day}.txt");
+    Path::new(&whole)
+        .is_file()
+        .then_some(whole)
+        .into_iter()
+        .chain(parts.into_iter().map(|(_, path)| path))
+        .collect()
+}
+
+/// Work that blocks the dashboard, deferred by one frame so a progress message can be drawn first.
+#[derive(Clone, Copy)]
+enum Task {
+    Run(Day),
+    Bench(Day),
+}
+
+enum View {
+    Calendar,
+    Text {
+        title: String,
+        text: String,
+        scroll: u16,
+    },
+}
+
+struct DayEntry {
+    day: Day,
+    title: Option<String>,
+    accepted: Vec<u8>,
+    answers: [Option<String>; 2],
+}
+
+impl DayEntry {
+    fn read(day: Day) -> Self {
+        Self {
+            day,
+            title: metadata::get_title(day),
+            accepted: metadata::get_accepted(day),
+            answers: [None, None],
+        }
+    }
+}
+
+struct App {
+    year: Option<u16>,
+    days: Vec<DayEntry>,
+    timings: Timings,
+    state: TableState,
+    view: View,
+    output: Vec<String>,
+    pending: Option<Task>,
+}
+
+/// Removes ANSI escape sequences and overwritten intermediate results from a line of runner output.
+fn clean_line(line: &str) -> String {
+    let line = line.rsplit('\r').next().unwrap_or(line);
+    let mut result = String::with_capacity(line.len());
+    let mut chars = line.chars();
+
+    while let Some(c) = chars.next() {
+        if c == '\x1b' {
+            chars.by_ref().find(char::is_ascii_alphabetic);
+        } else {
+            result.push(c);
+        }
+    }
+
+    result
+}
+
+impl App {
+    fn new() -> Self {
+        Self {
+            year: aoc_cli::get_year(),
+            days: all_days().map(DayEntry::read).collect(),
+            timings: Timings::read_from_file(),
+            state: TableState::default().with_selected(Some(0)),
+            view: View::Calendar,
+            output: vec![],
+            pending: None,
+        }
+    }
+
+    fn selected(&self) -> &DayEntry {
+        &self.days[self.state.selected().unwrap_or(0)]
+    }
+
+    fn status(&self, entry: &DayEntry) -> &'static str {
+        if !Path::new(&get_path_for_bin(entry.day)).exists() {
+            "not scaffolded"
+        } else if is_solved(&self.timings, entry.day, &entry.accepted) {
+            "solved"
+        } else {
+            "in progress"
+        }
+    }
+
+    fn open_file(&mut self, path: &str, hint: &str) {
+        match fs::read_to_string(path) {
+            Ok(text) => {
+                self.view = View::Text {
+                    title: path.into(),
+                    text,
+                    scroll: 0,
+                }
+            }
+            Err(_) => self.output = vec![format!("Could not read \"{path}\". {hint}")],
+        }
+    }
+
+    /// Shows all example files of the day, one after another if they are split per part.
+    fn open_examples(&mut self, day: Day) {
+        let paths = example_paths(day);
+        if let [path] = &paths[..] {
+            return self.open_file(path, "");
+        }
+        let texts: Vec<_> = paths
+            .iter()
+            .filter_map(|path| Some(format!("── {path} ──\n{}", fs::read_to_string(path).ok()?)))
+            .collect();
+        if texts.is_empty() {
+            self.output = vec![format!(
+                "Day {day} has no example in \"data/examples\". Save it as \"{day}.txt\", or as \"{day}-1.txt\" and \"{day}-2.txt\" for one per part."
+            )];
+            return;
+        }
+        self.view = View::Text {
+            title: format!("data/examples/{day}-*.txt"),
+            text: texts.join("\n"),
+            scroll: 0,
+        };
+    }
+
+    /// Handles a key press, returns `false` when the dashboard should close.
+    fn handle_key(&mut self, code: KeyCode) -> bool {
+        if let View::Text { scroll, .. } = &mut self.view {
+            match code {
+                KeyCode::Char('q') | KeyCode::Esc => self.view = View::Calendar,
+                KeyCode::Down | KeyCode::Char('j') => *scroll = scroll.saturating_add(1),
+                KeyCode::Up | KeyCode::Char('k') => *scroll = scroll.saturating_sub(1),
+                KeyCode::PageDown => *scroll = scroll.saturating_add(20),
+                KeyCode::PageUp => *scroll = scroll.saturating_sub(20),
+                _ => {}
+            }
+            return true;
+        }
+
+        let day = self.selected().day;
+        match code {
+            KeyCode::Char('q') | KeyCode::Esc => return false,
+            KeyCode::Down | KeyCode::Char('j') => self.state.select_next(),
+            KeyCode::Up | KeyCode::Char('k') => self.state.select_previous(),
+            KeyCode::Char('r') => {
+                self.output = vec![format!("Running day {day}...")];
+                self.pending = Some(Task::Run(day));
+            }
+            KeyCode::Char('b') => {
+                self.output = vec![format!("Benching day {day}...")];
+                self.pending = Some(Task::Bench(day));
+            }
+            KeyCode::Char('p') => self.open_file(
+                &format!("data/puzzles/{day}.md"),
+                &format!("Run `cargo download {day}` or `cargo read {day}` first."),
+            ),
+            KeyCode::Char('e') => self.open_examples(day),
+            _ => {}
+        }
+
+        // `select_next` may move past the last row.
+        if self.state.selected().is_some_and(|i| i >= self.days.len()) {
+            self.state.select(Some(self.days.len() - 1));
+        }
+        true
+    }
+
+    fn execute(&mut self, task: Task) {
+        let (day, is_timed) = match task {
+            Task::Run(day) => (day, false),
+            Task::Bench(day) => (day, true),
+        };
+
+        let output = match child_commands::run_solution_quiet(day, is_timed, true) {
+            Ok(output) if output.is_empty() => vec![format!("Day {day} is not scaffolded.")],
+            Ok(output) => output,
+            Err(e) => vec![format!("Failed to run day {day}: {e:?}")],
+        };
+
+        let answers = child_commands::parse_answers(&output);
+        if let Some(entry) = self.days.iter_mut().find(|entry| entry.day == day) {
+            entry.answers = answers;
+        }
+
+        if is_timed {
+            let timing = child_commands::parse_exec_time(&output, day);
+            self.timings = self.timings.merge(&Timings { data: vec![timing] });
+        }
+
+        self.output = output.iter().map(|line| clean_line(line)).collect();
+    }
+
+    fn draw(&mut self, frame: &mut Frame) {
+        let [header, main, output, footer] = Layout::vertical([
+            Constraint::Length(1),
+            Constraint::Min(8),
+            Constraint::Length(8),
+            Constraint::Length(1),
+        ])
+        .areas(frame.area());
+
+        let stars: usize = self.days.iter().map(|entry| entry.accepted.len()).sum();
+        let title = match self.year {
+            Some(year) => format!("Advent of Code {year} - {stars} stars"),
+            None => format!("Advent of Code - {stars} stars"),
+        };
+        frame.render_widget(Line::from(title).bold(), header);
+
+        match &self.view {
+            View::Calendar => self.draw_calendar(frame, main),
+            View::Text {
+                title,
+                text,
+                scroll,
+            } => frame.render_widget(
+                Paragraph::new(text.as_str())
+                    .block(Block::bordered().title(title.as_str()))
+                    .wrap(Wrap { trim: false })
+                    .scroll((*scroll, 0)),
+                main,
+            ),
+        }
+
+        let visible = usize::from(output.height.saturating_sub(2));
+        let lines: Vec<Line> = self
+            .output
+            .iter()
+            .skip(self.output.len().saturating_sub(visible))
+            .map(|line| Line::from(line.as_str()))
+            .collect();
+        frame.render_widget(
+            Paragraph::new(lines).block(Block::bordered().title("Output")),
+            output,
+        );
+
+        let keys = match self.view {
+            View::Calendar => "↑/↓ select · r run · b bench · p puzzle · e example · q quit",
+            View::Text { .. } => "↑/↓ scroll · PgUp/PgDn page · q back",
+        };
+        frame.render_widget(Line::from(keys).italic(), footer);
+    }
+
+    fn draw_calendar(&mut self, frame: &mut Frame, area: Rect) {
+        let rows: Vec<Row> = self
+            .days
+            .iter()
+            .map(|entry| {
+                let timing = self.timings.data.iter().find(|t| t.day == entry.day);
+                let [answer_1, answer_2] = entry.answers.each_ref().map(|answer| match answer {
+                    Some(x) if x.contains('\n') => "▼".to_string(),
+                    Some(x) => x.clone(),
+                    None => "-".into(),
+                });
+                let [time_1, time_2] = [1, 2].map(|part| {
+                    let time = timing.and_then(|t| {
+                        if part == 1 {
+                            t.part_1.as_ref()
+                        } else {
+                            t.part_2.as_ref()
+                        }
+                    });
+                    match (time, timing) {
+                        (Some(time), _) => time.clone(),
+                        (None, Some(t)) if t.not_implemented.contains(&part) => "n/a".into(),
+                        _ => "-".into(),
+                    }
+                });
+
+                let memory = timing
+                    .and_then(|t| t.peak_bytes)
+                    .map_or_else(|| "-".into(), format_bytes);
+
+                Row::new(vec![
+                    entry.day.to_string(),
+                    entry.title.clone().unwrap_or_default(),
+                    "*".repeat(entry.accepted.len()),
+                    self.status(entry).into(),
+                    answer_1,
+                    answer_2,
+                    time_1,
+                    time_2,
+                    memory,
+                ])
+            })
+            .collect();
+
+        let widths = [
+            Constraint::Length(3),
+            Constraint::Fill(2),
+            Constraint::Length(5),
+            Constraint::Length(14),
+            Constraint::Fill(1),
+            Constraint::Fill(1),
+            Constraint::Length(10),
+            Constraint::Length(10),
+            Constraint::Length(10),
+        ];
+
+        let table = Table::new(rows, widths)
+            .header(
+                Row::new([
+                    "Day", "Title", "Stars", "Status", "Part 1", "Part 2", "Time 1", "Time 2",
+                    "Memory",
+                ])
+                .bold(),
+            )
+            .block(Block::bordered())
+            .row_highlight_style(Style::new().reversed());
+
+        frame.render_stateful_widget(table, area, &mut self.state);
+    }
+}
+
+fn run(terminal: &mut DefaultTerminal) -> io::Result<()> {
+    let mut app = App::new();
+
+    loop {
+        terminal.draw(|frame| app.draw(frame))?;
+
+        if let Some(task) = app.pending.take() {
+            app.execute(task);
+            continue;
+        }
+
+        if let Event::Key(key) = event::read()? {
+            if key.kind == KeyEventKind::Press && !app.handle_key(key.code) {
+                return Ok(());
+            }
+        }
+    }
+}
+
+/// Opens an interactive dashboard listing all days.
+pub fn handle() {
+    let mut terminal = ratatui::init();
+    let result = run(&mut terminal);
+    ratatui::restore();
+
+    if let Err(e) = result {
+        eprintln!("Dashboard failed: {e}");
+    }
+}
+
+#[cfg(feature = "test_lib")]
+mod tests {
+    use super::example_paths;
+    use crate::day;
+
+    #[test]
+    fn finds_examples_split_per_part() {
+        assert_eq!(example_paths(day!(1)), ["data/examples/01.txt"]);
+        assert_eq!(
+            example_paths(day!(12)),
+            [
+                "data/examples/12-0.txt",
+                "data/examples/12-1.txt",
+                "data/examples/12-2.txt",
+                "data/examples/12-3.txt"
+            ]
+        );
+    }
+}
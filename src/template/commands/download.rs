@@ -1,14 +1,22 @@
-use crate::template::{aoc_cli, Day};
+use crate::template::{aoc_cli, metadata, Day, DaySet};
 use std::process;
 
-pub fn handle(day: Day) {
+pub fn handle(days: &DaySet) {
     if aoc_cli::check().is_err() {
         eprintln!("command \"aoc\" not found or not callable. Try running \"cargo install aoc-cli\" to install it.");
         process::exit(1);
     }
 
+    days.resolve().into_iter().for_each(download);
+}
+
+fn download(day: Day) {
     if let Err(e) = aoc_cli::download(day) {
         eprintln!("failed to call aoc-cli: {e}");
         process::exit(1);
     };
+
+    if let Some(title) = metadata::record_title(day) {
+        println!("🎄 Recorded title \"{title}\".");
+    }
 }
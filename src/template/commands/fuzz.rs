@@ -0,0 +1,36 @@
+use std::process::{self, Command, Stdio};
+
+use crate::template::Day;
+
+/// Checks the fuzz targets registered by a day's solution.
+/// Exits with an error if an implementation disagrees with its reference.
+pub fn handle(day: Day, iterations: Option<u64>, seed: Option<u64>) {
+    let mut cmd_args = vec![
+        "run".to_string(),
+        "--release".to_string(),
+        "--quiet".to_string(),
+        "--bin".to_string(),
+        day.to_string(),
+        "--".to_string(),
+        "--fuzz".to_string(),
+    ];
+
+    if let Some(iterations) = iterations {
+        cmd_args.extend(["--iterations".to_string(), iterations.to_string()]);
+    }
+
+    if let Some(seed) = seed {
+        cmd_args.extend(["--seed".to_string(), seed.to_string()]);
+    }
+
+    let status = Command::new("cargo")
+        .args(&cmd_args)
+        .stdout(Stdio::inherit())
+        .stderr(Stdio::inherit())
+        .status()
+        .unwrap();
+
+    if !status.success() {
+        process::exit(1);
+    }
+}
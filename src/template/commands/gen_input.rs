@@ -0,0 +1,22 @@
+use std::io::{stdout, Write};
+
+use crate::generate::generator_for;
+use crate::template::Day;
+
+/// Prints a synthetic input for the puzzle of `day`.
+/// Without `--size`, the input is as large as a real one. Without `--seed`, the seed is 0.
+pub fn handle(day: Day, size: Option<usize>, seed: Option<u64>) {
+    let generator = generator_for(day);
+    let size = size.unwrap_or(generator.default_size);
+
+    if !(generator.min_size..=generator.max_size).contains(&size) {
+        eprintln!(
+            "Size {size} is out of range for day {day}, clamping it to {}..={} {}.",
+            generator.min_size, generator.max_size, generator.size
+        );
+    }
+
+    let input = generator.generate(size, seed.unwrap_or(0));
+    // ignore a closed pipe, e.g. `cargo gen-input 9 | head`.
+    let _ = stdout().write_all(input.as_bytes());
+}
@@ -1,6 +1,15 @@
 pub mod all;
+#[cfg(feature = "tui")]
+pub mod dashboard;
 pub mod download;
+pub mod fuzz;
+pub mod gen_input;
 pub mod read;
 pub mod scaffold;
 pub mod solve;
+pub mod status;
+pub mod test;
 pub mod time;
+pub mod titles;
+pub mod wait_and_fetch;
+pub mod watch;
@@ -1,14 +1,22 @@
 use std::{
-    fs::{File, OpenOptions},
-    io::Write,
+    env,
+    fs::{self, File, OpenOptions},
+    io::{ErrorKind, Write},
+    path::PathBuf,
     process,
 };
 
-use crate::template::Day;
+use crate::template::{aoc_cli, metadata, Day};
 
 const MODULE_TEMPLATE: &str =
     include_str!(concat!(env!("CARGO_MANIFEST_DIR"), "/src/template.txt"));
 
+/// Module that compiles every day into the library, see `crate::days`.
+const REGISTRY_PATH: &str = "src/days/mod.rs";
+
+/// Directory that holds named module templates, e.g. `templates/grid.txt`.
+const DEFAULT_TEMPLATE_DIR: &str = "templates";
+
 fn safe_create_file(path: &str, overwrite: bool) -> Result<File, std::io::Error> {
     let mut file = OpenOptions::new();
     if overwrite {
@@ -19,19 +27,162 @@ fn safe_create_file(path: &str, overwrite: bool) -> Result<File, std::io::Error>
     file.truncate(true).write(true).open(path)
 }
 
-fn create_file(path: &str) -> Result<File, std::io::Error> {
-    OpenOptions::new()
-        .write(true)
-        .create(true)
-        .truncate(true)
-        .open(path)
+/// Creates an empty file unless it exists, so an input downloaded before scaffolding is kept.
+/// Returns whether the file was created.
+fn create_missing_file(path: &str) -> Result<bool, std::io::Error> {
+    match OpenOptions::new().write(true).create_new(true).open(path) {
+        Ok(_) => Ok(true),
+        Err(e) if e.kind() == ErrorKind::AlreadyExists => Ok(false),
+        Err(e) => Err(e),
+    }
+}
+
+fn get_template_dir() -> PathBuf {
+    env::var("AOC_TEMPLATE_DIR").map_or_else(|_| DEFAULT_TEMPLATE_DIR.into(), PathBuf::from)
+}
+
+/// Names of the templates available in the template directory.
+fn list_templates() -> Vec<String> {
+    let mut names: Vec<String> = fs::read_dir(get_template_dir())
+        .map(|entries| {
+            entries
+                .filter_map(Result::ok)
+                .filter_map(|entry| {
+                    let path = entry.path();
+                    if path.extension()? != "txt" {
+                        return None;
+                    }
+                    Some(path.file_stem()?.to_string_lossy().into_owned())
+                })
+                .collect()
+        })
+        .unwrap_or_default();
+    names.sort();
+    names
+}
+
+fn read_template(name: Option<&str>) -> Result<String, String> {
+    let Some(name) = name else {
+        return Ok(MODULE_TEMPLATE.into());
+    };
+
+    let path = get_template_dir().join(format!("{name}.txt"));
+    fs::read_to_string(&path).map_err(|_| {
+        let available = list_templates();
+        if available.is_empty() {
+            format!("template \"{name}\" not found at \"{}\".", path.display())
+        } else {
+            format!(
+                "template \"{name}\" not found at \"{}\". Available templates: {}.",
+                path.display(),
+                available.join(", ")
+            )
+        }
+    })
+}
+
+/// Formats an example answer as the expression a test asserts against.
+/// Answers are integers, because every module template returns `Option<u64>`.
+fn format_answer(answer: Option<u64>) -> String {
+    match answer {
+        None => "None".into(),
+        Some(x) => format!("Some({x})"),
+    }
 }
 
-pub fn handle(day: Day, overwrite: bool) {
+/// Parses an `--example-one` / `--example-two` answer.
+pub fn parse_answer(answer: &str) -> Result<u64, String> {
+    answer.parse().map_err(|_| {
+        format!("example answer \"{answer}\" is not an integer, but module templates return `Option<u64>`")
+    })
+}
+
+/// Adds `day` to the `days!` list of the library, in order.
+/// Returns `None` if the day is listed already.
+fn register_day(registry: &str, day: Day) -> Result<Option<String>, String> {
+    const LIST_START: &str = "\ndays! {\n";
+    let start = registry
+        .rfind(LIST_START)
+        .map(|i| i + LIST_START.len())
+        .ok_or("the `days!` list is missing")?;
+    let end = registry[start..]
+        .find("\n}")
+        .map(|i| start + i + 1)
+        .ok_or("the `days!` list is not closed")?;
+
+    let name = format!("day{day}");
+    let mut offset = start;
+    for line in registry[start..end].split_inclusive('\n') {
+        let listed = line.trim_start().split(':').next().unwrap_or_default();
+        if listed == name {
+            return Ok(None);
+        }
+        if listed > name.as_str() {
+            break;
+        }
+        offset += line.len();
+    }
+
+    let mut registry = registry.to_owned();
+    registry.insert_str(offset, &format!("    {name}: \"../bin/{day}.rs\",\n"));
+    Ok(Some(registry))
+}
+
+/// Values substituted into a module template.
+struct Placeholders {
+    day: Day,
+    year: Option<u16>,
+    title: Option<String>,
+    example_answers: [Option<u64>; 2],
+}
+
+impl Placeholders {
+    fn render(&self, template: &str) -> String {
+        let day_number = self.day.into_inner().to_string();
+        let year = self.year.map(|y| y.to_string()).unwrap_or_default();
+        let url = metadata::puzzle_url(self.day, self.year);
+        let title = self
+            .title
+            .clone()
+            .unwrap_or_else(|| format!("Day {day_number}"));
+        let [example_one, example_two] = self.example_answers.map(format_answer);
+
+        template
+            .replace("%DAY_NUMBER%", &day_number)
+            .replace("%DAY%", &self.day.to_string())
+            .replace("%YEAR%", &year)
+            .replace("%TITLE%", &title)
+            .replace("%PUZZLE_URL%", &url)
+            .replace("%EXAMPLE_ONE%", &example_one)
+            .replace("%EXAMPLE_TWO%", &example_two)
+    }
+}
+
+pub fn handle(
+    day: Day,
+    overwrite: bool,
+    template: Option<&str>,
+    example_answers: [Option<u64>; 2],
+) {
     let input_path = format!("data/inputs/{day}.txt");
     let example_path = format!("data/examples/{day}.txt");
     let module_path = format!("src/bin/{day}.rs");
 
+    let template = match read_template(template) {
+        Ok(template) => template,
+        Err(e) => {
+            eprintln!("Failed to read module template: {e}");
+            process::exit(1);
+        }
+    };
+
+    let placeholders = Placeholders {
+        day,
+        year: aoc_cli::get_year(),
+        title: metadata::get_title(day).or_else(|| metadata::record_title(day)),
+        example_answers,
+    };
+
     let mut file = match safe_create_file(&module_path, overwrite) {
         Ok(file) => file,
         Err(e) => {
@@ -40,11 +191,7 @@ pub fn handle(day: Day, overwrite: bool) {
         }
     };
 
-    match file.write_all(
-        MODULE_TEMPLATE
-            .replace("%DAY_NUMBER%", &day.into_inner().to_string())
-            .as_bytes(),
-    ) {
+    match file.write_all(placeholders.render(&template).as_bytes()) {
         Ok(()) => {
             println!("Created module file \"{}\"", &module_path);
         }
@@ -54,20 +201,44 @@ pub fn handle(day: Day, overwrite: bool) {
         }
     }
 
-    match create_file(&input_path) {
-        Ok(_) => {
+    let registered = fs::read_to_string(REGISTRY_PATH)
+        .map_err(|e| e.to_string())
+        .and_then(|registry| register_day(&registry, day));
+    match registered {
+        Ok(Some(registry)) => match fs::write(REGISTRY_PATH, registry) {
+            Ok(()) => println!("Added day {day} to \"{REGISTRY_PATH}\""),
+            Err(e) => {
+                eprintln!("Failed to add day {day} to \"{REGISTRY_PATH}\": {e}");
+                process::exit(1);
+            }
+        },
+        Ok(None) => {}
+        Err(e) => {
+            eprintln!("Failed to add day {day} to \"{REGISTRY_PATH}\": {e}");
+            process::exit(1);
+        }
+    }
+
+    match create_missing_file(&input_path) {
+        Ok(true) => {
             println!("Created empty input file \"{}\"", &input_path);
         }
+        Ok(false) => {
+            println!("Kept existing input file \"{}\"", &input_path);
+        }
         Err(e) => {
             eprintln!("Failed to create input file: {e}");
             process::exit(1);
         }
     }
 
-    match create_file(&example_path) {
-        Ok(_) => {
+    match create_missing_file(&example_path) {
+        Ok(true) => {
             println!("Created empty example file \"{}\"", &example_path);
         }
+        Ok(false) => {
+            println!("Kept existing example file \"{}\"", &example_path);
+        }
         Err(e) => {
             eprintln!("Failed to create example file: {e}");
             process::exit(1);
@@ -77,3 +248,97 @@ pub fn handle(day: Day, overwrite: bool) {
     println!("---");
     println!("🎄 Type `cargo solve {day}` to run your solution.");
 }
+
+#[cfg(feature = "test_lib")]
+mod tests {
+    use std::{env, fs};
+
+    use super::{format_answer, parse_answer, register_day, Placeholders, MODULE_TEMPLATE};
+    use crate::day;
+    use crate::template::metadata;
+
+    #[test]
+    fn formats_answers() {
+        assert_eq!(format_answer(None), "None");
+        assert_eq!(format_answer(Some(161)), "Some(161)");
+    }
+
+    #[test]
+    fn rejects_non_integer_answers() {
+        assert_eq!(parse_answer("161"), Ok(161));
+        assert!(parse_answer("6,1").is_err());
+    }
+
+    #[test]
+    fn registers_new_days_in_order() {
+        let registry =
+            "days! {\n    day01: \"../bin/01.rs\",\n    day05: \"../bin/05.rs\" => parse,\n}\n";
+        let registry = format!("//! Days.\n{registry}");
+        assert_eq!(
+            register_day(&registry, day!(3)),
+            Ok(Some(
+                "//! Days.\ndays! {\n    day01: \"../bin/01.rs\",\n    day03: \"../bin/03.rs\",\n    day05: \"../bin/05.rs\" => parse,\n}\n".into()
+            ))
+        );
+        assert_eq!(
+            register_day(&registry, day!(7)),
+            Ok(Some(
+                "//! Days.\ndays! {\n    day01: \"../bin/01.rs\",\n    day05: \"../bin/05.rs\" => parse,\n    day07: \"../bin/07.rs\",\n}\n".into()
+            ))
+        );
+        assert_eq!(register_day(&registry, day!(5)), Ok(None));
+        assert!(register_day("//! No days.\n", day!(5)).is_err());
+
+        let library = fs::read_to_string(super::REGISTRY_PATH).unwrap();
+        assert_eq!(register_day(&library, day!(25)), Ok(None));
+    }
+
+    #[test]
+    fn renders_placeholders() {
+        let placeholders = Placeholders {
+            day: day!(3),
+            year: Some(2024),
+            title: Some("Mull It Over".into()),
+            example_answers: [Some(161), None],
+        };
+        let rendered = placeholders
+            .render("%DAY_NUMBER% %DAY% %YEAR% %TITLE% %PUZZLE_URL% %EXAMPLE_ONE% %EXAMPLE_TWO%");
+        assert_eq!(
+            rendered,
+            "3 03 2024 Mull It Over https://adventofcode.com/2024/day/3 Some(161) None"
+        );
+    }
+
+    #[test]
+    fn renders_missing_title() {
+        let placeholders = Placeholders {
+            day: day!(3),
+            year: None,
+            title: None,
+            example_answers: [None, None],
+        };
+        assert_eq!(placeholders.render("%TITLE%"), "Day 3");
+    }
+
+    #[test]
+    fn renders_title_of_downloaded_puzzle() {
+        let dir = env::temp_dir().join(format!("aoc-scaffold-{}", std::process::id()));
+        fs::create_dir_all(&dir).unwrap();
+        let puzzle_path = dir.join("03.md");
+        fs::write(
+            &puzzle_path,
+            "## \\-\\-\\- Day 3: Mull It Over \\-\\-\\-\n\n\"Our computers are having issues\"...",
+        )
+        .unwrap();
+
+        let placeholders = Placeholders {
+            day: day!(3),
+            year: Some(2024),
+            title: metadata::read_title(&puzzle_path),
+            example_answers: [None, None],
+        };
+        let module = placeholders.render(MODULE_TEMPLATE);
+        fs::remove_dir_all(&dir).unwrap();
+        assert!(module.starts_with("//! # Mull It Over\n"));
+    }
+}
@@ -1,8 +1,27 @@
-use std::process::{Command, Stdio};
+use std::process::{self, Command, Stdio};
 
-use crate::template::Day;
+use crate::template::{Day, DaySet, ANSI_BOLD, ANSI_RESET};
 
-pub fn handle(day: Day, release: bool, dhat: bool, submit_part: Option<u8>) {
+pub fn handle(days: &DaySet, release: bool, dhat: bool, submit_part: Option<u8>) {
+    let days = days.resolve();
+
+    if submit_part.is_some() && days.len() > 1 {
+        eprintln!("`--submit` can only be used when solving a single day.");
+        process::exit(1);
+    }
+
+    for (i, &day) in days.iter().enumerate() {
+        if days.len() > 1 {
+            if i > 0 {
+                println!();
+            }
+            println!("{ANSI_BOLD}Day {day}{ANSI_RESET}");
+        }
+        solve(day, release, dhat, submit_part);
+    }
+}
+
+fn solve(day: Day, release: bool, dhat: bool, submit_part: Option<u8>) {
     let mut cmd_args = vec!["run".to_string(), "--bin".to_string(), day.to_string()];
 
     if dhat {
@@ -0,0 +1,80 @@
+use std::collections::HashMap;
+
+use crate::template::{all_days, aoc_cli, metadata, readme_stars, Day};
+
+const WEEKDAYS: [&str; 7] = ["Mo", "Tu", "We", "Th", "Fr", "Sa", "Su"];
+
+/// Weekday of December 1st in `year`, counted from Monday (0) to Sunday (6).
+fn first_weekday(year: u16) -> usize {
+    // Sakamoto's method, with the month table entry for December.
+    let y = usize::from(year);
+    (y + y / 4 - y / 100 + y / 400 + 4 + 1 + 6) % 7
+}
+
+/// Renders the puzzle days as a calendar month, with one star per accepted part.
+fn render_calendar(year: Option<u16>, accepted: &HashMap<Day, Vec<u8>>) -> String {
+    let mut lines = vec![match year {
+        Some(year) => format!("December {year}"),
+        None => "December".into(),
+    }];
+    lines.push(WEEKDAYS.join("   "));
+
+    let offset = year.map_or(0, first_weekday);
+    let mut cells = vec![" ".repeat(5); offset];
+    cells.extend(all_days().map(|day| {
+        let stars = "*".repeat(accepted.get(&day).map_or(0, Vec::len));
+        format!("{day}{stars:<3}")
+    }));
+
+    lines.extend(
+        cells
+            .chunks(7)
+            .map(|week| week.concat().trim_end().to_string()),
+    );
+
+    let total: usize = accepted.values().map(Vec::len).sum();
+    lines.push(String::new());
+    lines.push(format!("Total: {total} stars"));
+    lines.join("\n")
+}
+
+pub fn handle() {
+    let accepted = metadata::get_all_accepted();
+    println!("{}", render_calendar(aoc_cli::get_year(), &accepted));
+
+    println!();
+    match readme_stars::update(&accepted) {
+        Ok(()) => {
+            println!("Stored updated progress.");
+        }
+        Err(_) => {
+            eprintln!("Failed to store updated progress.");
+        }
+    }
+}
+
+#[cfg(feature = "test_lib")]
+mod tests {
+    use super::{first_weekday, render_calendar};
+    use crate::day;
+    use std::collections::HashMap;
+
+    #[test]
+    fn finds_first_weekday() {
+        assert_eq!(first_weekday(2024), 6);
+        assert_eq!(first_weekday(2023), 4);
+        assert_eq!(first_weekday(2020), 1);
+    }
+
+    #[test]
+    fn renders_calendar() {
+        let accepted = HashMap::from([(day!(1), vec![1, 2]), (day!(2), vec![1])]);
+        let calendar = render_calendar(Some(2024), &accepted);
+        let lines: Vec<_> = calendar.lines().collect();
+        assert_eq!(lines[0], "December 2024");
+        assert_eq!(lines[1], "Mo   Tu   We   Th   Fr   Sa   Su");
+        assert_eq!(lines[2], format!("{}01**", " ".repeat(30)));
+        assert_eq!(lines[3], "02*  03   04   05   06   07   08");
+        assert_eq!(lines.last(), Some(&"Total: 3 stars"));
+    }
+}
@@ -0,0 +1,225 @@
+use std::fs;
+use std::path::Path;
+use std::process::{self, Command};
+
+use crate::template::run_multi::get_path_for_bin;
+use crate::template::{Day, DaySet, ANSI_BOLD, ANSI_RESET};
+
+/// Example test coverage of a single part.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum Coverage {
+    Passed,
+    Failed,
+    /// The test passes, but still asserts the `None` of the scaffolded template.
+    Placeholder,
+    Missing,
+    /// The solution has no function for the part, e.g. the second part of day 25.
+    NotImplemented,
+}
+
+impl Coverage {
+    fn symbol(self) -> &'static str {
+        match self {
+            Coverage::Passed => "✔",
+            Coverage::Failed => "✖",
+            Coverage::Placeholder => "?",
+            Coverage::Missing => "-",
+            Coverage::NotImplemented => "n/a",
+        }
+    }
+}
+
+/// The part a test covers, derived from its name, e.g. `tests::test_part_one`.
+fn part_of(test_name: &str) -> Option<u8> {
+    if test_name.contains("part_one") {
+        Some(1)
+    } else if test_name.contains("part_two") {
+        Some(2)
+    } else {
+        None
+    }
+}
+
+/// Parses the `test <name> ... ok` lines of libtest output into `(part, passed)` pairs.
+fn parse_test_results(output: &str) -> Vec<(u8, bool)> {
+    output
+        .lines()
+        .filter_map(|line| {
+            let (name, result) = line.strip_prefix("test ")?.split_once(" ... ")?;
+            let passed = match result.trim() {
+                "ok" => true,
+                "FAILED" => false,
+                _ => return None,
+            };
+            Some((part_of(name)?, passed))
+        })
+        .collect()
+}
+
+/// Parts whose test still asserts `None`, as scaffolded from the template.
+fn find_placeholders(source: &str) -> Vec<u8> {
+    source
+        .split("#[test]")
+        .skip(1)
+        .filter_map(|test| {
+            let name = test.split_once("fn ")?.1.split('(').next()?;
+            let is_placeholder = test.lines().any(|line| {
+                let line = line.trim();
+                line.starts_with("assert_eq!(") && line.ends_with(", None);")
+            });
+            is_placeholder.then_some(part_of(name)?)
+        })
+        .collect()
+}
+
+fn coverage(results: &[(u8, bool)], placeholders: &[u8], part: u8) -> Coverage {
+    let mut outcomes = results.iter().filter(|(p, _)| *p == part).peekable();
+
+    if outcomes.peek().is_none() {
+        Coverage::Missing
+    } else if outcomes.any(|(_, passed)| !passed) {
+        Coverage::Failed
+    } else if placeholders.contains(&part) {
+        Coverage::Placeholder
+    } else {
+        Coverage::Passed
+    }
+}
+
+fn render_grid(rows: &[(Day, [Coverage; 2])]) -> String {
+    let mut lines = vec!["Day  Part 1  Part 2".to_string()];
+    lines.extend(rows.iter().map(|(day, [part_1, part_2])| {
+        format!("{day}   {:<6}  {}", part_1.symbol(), part_2.symbol())
+    }));
+
+    let parts: Vec<_> = rows
+        .iter()
+        .flat_map(|(_, parts)| parts)
+        .filter(|c| **c != Coverage::NotImplemented)
+        .collect();
+    let passed = parts.iter().filter(|c| ***c == Coverage::Passed).count();
+    lines.push(String::new());
+    lines.push(format!(
+        "Coverage: {passed}/{} parts with passing example tests.",
+        parts.len()
+    ));
+    lines.push(
+        "✔ passed · ✖ failed · ? still asserts `None` · - no test · n/a not implemented".into(),
+    );
+    lines.join("\n")
+}
+
+/// Tests a single day, printing the test output if anything fails.
+fn test_day(day: Day) -> [Coverage; 2] {
+    let output = Command::new("cargo")
+        .args(["test", "--bin", &day.to_string()])
+        .output();
+
+    let output = match output {
+        Ok(output) => output,
+        Err(e) => {
+            eprintln!("Failed to run tests for day {day}: {e}");
+            return [Coverage::Failed; 2];
+        }
+    };
+
+    let stdout = String::from_utf8_lossy(&output.stdout);
+    let results = parse_test_results(&stdout);
+    let source = fs::read_to_string(get_path_for_bin(day)).unwrap_or_default();
+    let placeholders = find_placeholders(&source);
+
+    if !output.status.success() {
+        println!("{ANSI_BOLD}Day {day}{ANSI_RESET}");
+        print!("{stdout}");
+        eprint!("{}", String::from_utf8_lossy(&output.stderr));
+        println!();
+
+        // the bin did not compile.
+        if results.is_empty() {
+            return [Coverage::Failed; 2];
+        }
+    }
+
+    [("part_one", 1), ("part_two", 2)].map(|(name, part)| {
+        if source.contains(&format!("fn {name}(")) {
+            coverage(&results, &placeholders, part)
+        } else {
+            Coverage::NotImplemented
+        }
+    })
+}
+
+/// Runs the tests of every selected, scaffolded day and prints a coverage grid of their parts.
+/// Exits with an error if any of the tests fail.
+pub fn handle(days: &DaySet) {
+    let rows: Vec<_> = days
+        .resolve()
+        .into_iter()
+        .filter(|day| Path::new(&get_path_for_bin(*day)).exists())
+        .map(|day| (day, test_day(day)))
+        .collect();
+
+    println!("{}", render_grid(&rows));
+
+    let failed: Vec<_> = rows
+        .iter()
+        .filter(|(_, parts)| parts.contains(&Coverage::Failed))
+        .map(|(day, _)| day.to_string())
+        .collect();
+
+    if !failed.is_empty() {
+        eprintln!("Tests failed for day(s): {}.", failed.join(", "));
+        process::exit(1);
+    }
+}
+
+#[cfg(feature = "test_lib")]
+mod tests {
+    use super::{coverage, find_placeholders, parse_test_results, render_grid, Coverage};
+    use crate::day;
+
+    #[test]
+    fn parses_test_results() {
+        let output = [
+            "running 3 tests",
+            "test tests::test_part_one ... ok",
+            "test tests::test_part_two ... FAILED",
+            "test tests::test_parse ... ok",
+            "test tests::test_part_two_small ... ignored",
+        ]
+        .join("\n");
+        assert_eq!(parse_test_results(&output), vec![(1, true), (2, false)]);
+    }
+
+    #[test]
+    fn finds_placeholders() {
+        let source = include_str!(concat!(env!("CARGO_MANIFEST_DIR"), "/src/template.txt"))
+            .replace("%EXAMPLE_ONE%", "Some(11)")
+            .replace("%EXAMPLE_TWO%", "None");
+        assert_eq!(find_placeholders(&source), vec![2]);
+    }
+
+    #[test]
+    fn combines_coverage() {
+        let results = [(1, true), (1, false), (2, true)];
+        assert_eq!(coverage(&results, &[], 1), Coverage::Failed);
+        assert_eq!(coverage(&results, &[], 2), Coverage::Passed);
+        assert_eq!(coverage(&results, &[2], 2), Coverage::Placeholder);
+        assert_eq!(coverage(&[], &[], 2), Coverage::Missing);
+    }
+
+    #[test]
+    fn renders_grid() {
+        let grid = render_grid(&[
+            (day!(1), [Coverage::Passed, Coverage::Passed]),
+            (day!(24), [Coverage::Placeholder, Coverage::Missing]),
+            (day!(25), [Coverage::Failed, Coverage::NotImplemented]),
+        ]);
+        let lines: Vec<_> = grid.lines().collect();
+        assert_eq!(lines[0], "Day  Part 1  Part 2");
+        assert_eq!(lines[1], "01   ✔       ✔");
+        assert_eq!(lines[2], "24   ?       -");
+        assert_eq!(lines[3], "25   ✖       n/a");
+        assert_eq!(lines[5], "Coverage: 2/5 parts with passing example tests.");
+    }
+}
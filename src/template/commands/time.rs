@@ -1,13 +1,36 @@
 use std::collections::HashSet;
+use std::path::Path;
+use std::process::{self, Command, Stdio};
 
-use crate::template::run_multi::run_multi;
-use crate::template::timings::Timings;
-use crate::template::{all_days, readme_benchmarks, Day};
+use crate::template::run_multi::{child_commands, get_path_for_bin, run_multi};
+use crate::template::timings::{format_bytes, Timings};
+use crate::template::{all_days, readme_benchmarks, Day, DaySet, ANSI_BOLD, ANSI_RESET};
+
+pub fn handle(
+    days: Option<&DaySet>,
+    run_all: bool,
+    store: bool,
+    memory: bool,
+    scale: Option<u32>,
+    truncate: bool,
+) {
+    if let Some(steps) = scale {
+        if store || memory {
+            eprintln!("`--store` and `--memory` cannot be combined with `--scale`.");
+            process::exit(1);
+        }
+        if steps < 2 {
+            eprintln!("`--scale` needs at least 2 input sizes to fit a curve.");
+            process::exit(1);
+        }
+        let days = days.map_or_else(|| all_days().collect(), DaySet::resolve);
+        handle_scale(&days, steps, truncate);
+        return;
+    }
 
-pub fn handle(day: Option<Day>, run_all: bool, store: bool) {
     let stored_timings = Timings::read_from_file();
 
-    let days_to_run = day.map_or_else(
+    let days_to_run: HashSet<Day> = days.map_or_else(
         || {
             if run_all {
                 all_days().collect()
@@ -18,10 +41,13 @@ pub fn handle(day: Option<Day>, run_all: bool, store: bool) {
                     .collect()
             }
         },
-        |day| HashSet::from([day]),
+        |days| days.resolve().into_iter().collect(),
     );
 
-    let timings = run_multi(&days_to_run, true, true).unwrap();
+    let mut timings = run_multi(&days_to_run, true, true).unwrap();
+    if memory {
+        measure_memory(&mut timings);
+    }
 
     if store {
         let merged_timings = stored_timings.merge(&timings);
@@ -38,3 +64,59 @@ pub fn handle(day: Option<Day>, run_all: bool, store: bool) {
         }
     }
 }
+
+/// Adds the peak heap usage of every timed day, measured in a separate run with DHAT.
+/// DHAT slows solutions down too much to do both in the same run.
+fn measure_memory(timings: &mut Timings) {
+    println!();
+    for timing in &mut timings.data {
+        match child_commands::measure_memory(timing.day) {
+            Ok(Some(bytes)) => {
+                println!(
+                    "{ANSI_BOLD}Day {}{ANSI_RESET} peak heap: {}",
+                    timing.day,
+                    format_bytes(bytes)
+                );
+                timing.peak_bytes = Some(bytes);
+            }
+            Ok(None) => eprintln!("Could not measure the memory of day {}.", timing.day),
+            Err(e) => eprintln!("Failed to run day {} with DHAT: {e:?}", timing.day),
+        }
+    }
+}
+
+/// Times every part of the scaffolded `days` across inputs of increasing size, see [`crate::template::runner::run_scaled`].
+fn handle_scale(days: &[Day], steps: u32, truncate: bool) {
+    let days: Vec<_> = days
+        .iter()
+        .filter(|day| Path::new(&get_path_for_bin(**day)).exists())
+        .collect();
+
+    for (i, day) in days.iter().enumerate() {
+        if i > 0 {
+            println!();
+        }
+        println!("{ANSI_BOLD}Day {day}{ANSI_RESET}");
+
+        let mut cmd_args = vec![
+            "run".to_string(),
+            "--release".to_string(),
+            "--quiet".to_string(),
+            "--bin".to_string(),
+            day.to_string(),
+            "--".to_string(),
+            "--scale".to_string(),
+            steps.to_string(),
+        ];
+        if truncate {
+            cmd_args.push("--truncate".to_string());
+        }
+
+        Command::new("cargo")
+            .args(&cmd_args)
+            .stdout(Stdio::inherit())
+            .stderr(Stdio::inherit())
+            .status()
+            .unwrap();
+    }
+}
@@ -0,0 +1,35 @@
+use std::path::Path;
+
+use crate::template::run_multi::get_path_for_bin;
+use crate::template::{all_days, aoc_cli, metadata, Day};
+
+fn fetch_title(day: Day) -> Option<String> {
+    if let Some(title) = metadata::record_title(day) {
+        return Some(title);
+    }
+
+    if aoc_cli::check().is_err() {
+        eprintln!("command \"aoc\" not found or not callable. Try running \"cargo install aoc-cli\" to install it.");
+        return None;
+    }
+
+    if let Err(e) = aoc_cli::download_puzzle(day) {
+        eprintln!("failed to call aoc-cli: {e}");
+        return None;
+    }
+
+    metadata::record_title(day)
+}
+
+/// Records the title of every scaffolded day, downloading puzzle descriptions where necessary.
+pub fn handle() {
+    all_days()
+        .filter(|day| Path::new(&get_path_for_bin(*day)).exists())
+        .for_each(|day| {
+            let title = metadata::get_title(day).or_else(|| fetch_title(day));
+            match title {
+                Some(title) => println!("Day {day}: {title}"),
+                None => eprintln!("Day {day}: could not determine title."),
+            }
+        });
+}
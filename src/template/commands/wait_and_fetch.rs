@@ -0,0 +1,47 @@
+use std::io::{stdout, Write};
+use std::process;
+use std::time::Duration;
+
+use crate::template::commands::{download, read, scaffold};
+use crate::template::unlock::{format_countdown, wait_for_next_unlock, Clock, SystemClock};
+use crate::template::{aoc_cli, ANSI_BOLD, ANSI_RESET};
+
+/// Time to wait after the unlock before fetching, so the puzzle is available when aoc-cli asks for it.
+const UNLOCK_GRACE: Duration = Duration::from_secs(2);
+
+/// Sleeps until the next puzzle unlocks, then downloads, scaffolds and reads it.
+pub fn handle() {
+    let Some(year) = aoc_cli::get_year() else {
+        eprintln!("`wait-and-fetch` requires the `AOC_YEAR` environment variable to be set.");
+        process::exit(1);
+    };
+
+    if aoc_cli::check().is_err() {
+        eprintln!("command \"aoc\" not found or not callable. Try running \"cargo install aoc-cli\" to install it.");
+        process::exit(1);
+    }
+
+    let clock = SystemClock;
+    let day = wait_for_next_unlock(&clock, year, |day, remaining| {
+        print!(
+            "\r{ANSI_BOLD}Day {day}{ANSI_RESET} unlocks in {}  ",
+            format_countdown(remaining)
+        );
+        let _ = stdout().flush();
+    });
+
+    let Some(day) = day else {
+        eprintln!("All puzzles of {year} are unlocked already.");
+        process::exit(1);
+    };
+
+    println!(
+        "\r{ANSI_BOLD}Day {day}{ANSI_RESET} unlocked!{}",
+        " ".repeat(16)
+    );
+    clock.sleep(UNLOCK_GRACE);
+
+    download::handle(&day.into());
+    scaffold::handle(day, false, None, [None, None]);
+    read::handle(day);
+}
@@ -0,0 +1,201 @@
+use std::{
+    collections::HashMap,
+    fs,
+    path::PathBuf,
+    process::{Command, Stdio},
+    thread,
+    time::{Duration, SystemTime},
+};
+
+use crate::template::run_multi::{child_commands, get_path_for_bin};
+use crate::template::{Day, ANSI_BOLD, ANSI_ITALIC, ANSI_RESET};
+
+/// How often watched files are checked for modifications.
+const POLL_INTERVAL: Duration = Duration::from_millis(500);
+
+type Answers = [Option<String>; 2];
+
+/// Modification times of the day's source, input and example files.
+fn snapshot(day: Day) -> HashMap<PathBuf, SystemTime> {
+    let mut paths = vec![
+        PathBuf::from(get_path_for_bin(day)),
+        PathBuf::from(format!("data/inputs/{day}.txt")),
+    ];
+
+    // examples may be split per part, e.g. `01-2.txt`.
+    if let Ok(entries) = fs::read_dir("data/examples") {
+        paths.extend(
+            entries
+                .filter_map(Result::ok)
+                .map(|entry| entry.path())
+                .filter(|path| {
+                    path.file_name()
+                        .is_some_and(|name| name.to_string_lossy().starts_with(&day.to_string()))
+                }),
+        );
+    }
+
+    paths
+        .into_iter()
+        .filter_map(|path| {
+            let modified = fs::metadata(&path).and_then(|m| m.modified()).ok()?;
+            Some((path, modified))
+        })
+        .collect()
+}
+
+fn changed_paths(
+    previous: &HashMap<PathBuf, SystemTime>,
+    current: &HashMap<PathBuf, SystemTime>,
+) -> Vec<String> {
+    let mut paths: Vec<_> = current
+        .iter()
+        .filter(|(path, modified)| previous.get(*path) != Some(modified))
+        .map(|(path, _)| path)
+        .chain(previous.keys().filter(|path| !current.contains_key(*path)))
+        .map(|path| path.display().to_string())
+        .collect();
+    paths.sort();
+    paths
+}
+
+/// Runs the day's tests, printing their output only if they fail.
+fn run_tests(day: Day) -> bool {
+    let output = Command::new("cargo")
+        .args(["test", "--quiet", "--bin", &day.to_string()])
+        .stdout(Stdio::piped())
+        .stderr(Stdio::piped())
+        .output();
+
+    match output {
+        Ok(output) if output.status.success() => {
+            println!("Example tests passed.");
+            true
+        }
+        Ok(output) => {
+            print!("{}", String::from_utf8_lossy(&output.stdout));
+            eprint!("{}", String::from_utf8_lossy(&output.stderr));
+            println!("Example tests failed, skipping the real input.");
+            false
+        }
+        Err(e) => {
+            eprintln!("Failed to run tests: {e}");
+            false
+        }
+    }
+}
+
+fn format_answer(answer: Option<&str>) -> String {
+    match answer {
+        None => "✖".into(),
+        Some(x) if x.contains('\n') => format!("▼ ({} lines)", x.lines().count()),
+        Some(x) => x.into(),
+    }
+}
+
+/// Summarizes how the answers changed since the last successful run, e.g. `Part 1: 41 → 42, Part 2: unchanged`.
+fn format_diff(previous: &Answers, current: &Answers) -> String {
+    [1, 2]
+        .iter()
+        .zip(previous.iter().zip(current))
+        .map(|(part, (before, after))| {
+            if before == after {
+                format!("Part {part}: unchanged")
+            } else {
+                format!(
+                    "Part {part}: {} → {}",
+                    format_answer(before.as_deref()),
+                    format_answer(after.as_deref())
+                )
+            }
+        })
+        .collect::<Vec<_>>()
+        .join(", ")
+}
+
+pub fn handle(day: Day) {
+    println!("Watching day {day}, press Ctrl-C to stop.");
+
+    let mut files = HashMap::new();
+    let mut answers: Option<Answers> = None;
+    let mut iteration = 0;
+
+    loop {
+        let current = snapshot(day);
+        let changed = changed_paths(&files, &current);
+        files = current;
+
+        if changed.is_empty() {
+            thread::sleep(POLL_INTERVAL);
+            continue;
+        }
+
+        iteration += 1;
+        println!();
+        println!(
+            "{ANSI_BOLD}Day {day} #{iteration}{ANSI_RESET} {ANSI_ITALIC}{}{ANSI_RESET}",
+            changed.join(", ")
+        );
+
+        if !run_tests(day) {
+            continue;
+        }
+
+        let output = match child_commands::run_solution(day, false, true) {
+            Ok(output) => output,
+            Err(e) => {
+                eprintln!("Failed to run solution: {e:?}");
+                continue;
+            }
+        };
+
+        let current_answers = child_commands::parse_answers(&output);
+        if let Some(previous_answers) = &answers {
+            println!("{}", format_diff(previous_answers, &current_answers));
+        }
+        answers = Some(current_answers);
+    }
+}
+
+#[cfg(feature = "test_lib")]
+mod tests {
+    use super::{changed_paths, format_diff};
+    use std::{
+        collections::HashMap,
+        path::PathBuf,
+        time::{Duration, SystemTime},
+    };
+
+    #[test]
+    fn detects_changed_paths() {
+        let t = SystemTime::UNIX_EPOCH;
+        let previous = HashMap::from([
+            (PathBuf::from("a"), t),
+            (PathBuf::from("b"), t),
+            (PathBuf::from("c"), t),
+        ]);
+        let current = HashMap::from([
+            (PathBuf::from("a"), t),
+            (PathBuf::from("b"), t + Duration::from_secs(1)),
+            (PathBuf::from("d"), t),
+        ]);
+        assert_eq!(changed_paths(&previous, &current), vec!["b", "c", "d"]);
+        assert_eq!(changed_paths(&current, &current).len(), 0);
+    }
+
+    #[test]
+    fn formats_diff() {
+        let previous = [Some("41".into()), None];
+        let current = [Some("42".into()), None];
+        assert_eq!(
+            format_diff(&previous, &current),
+            "Part 1: 41 → 42, Part 2: unchanged"
+        );
+
+        let current = [Some("41".into()), Some("#.\n.#".into())];
+        assert_eq!(
+            format_diff(&previous, &current),
+            "Part 1: unchanged, Part 2: ✖ → ▼ (2 lines)"
+        );
+    }
+}
@@ -0,0 +1,217 @@
+use std::collections::{BTreeSet, HashMap};
+use std::error::Error;
+use std::fmt::Display;
+use std::str::FromStr;
+
+use crate::day;
+use crate::template::timings::Timings;
+use crate::template::{all_days, metadata, Day};
+
+/// A set of days selected on the command line.
+///
+/// The set is a comma-separated union of terms:
+///  - `8`: a single day.
+///  - `1-5`, `12-`, `-3`: a range of days, open ends extend to the 1st or the 25th.
+///  - `all`, `odd`, `even`: the respective days of advent.
+///  - `unsolved`: days that are neither benched completely nor have both answers accepted.
+///  - `slowest:5`: the five days with the highest stored total execution time.
+///
+/// For example, `1-3,8,24-` selects days 1, 2, 3, 8, 24 and 25.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct DaySet {
+    terms: Vec<Term>,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum Term {
+    Range(Day, Day),
+    Odd,
+    Even,
+    Unsolved,
+    Slowest(usize),
+}
+
+impl DaySet {
+    /// A set that contains every day of advent.
+    pub fn all() -> Self {
+        Self {
+            terms: vec![Term::Range(day!(1), day!(25))],
+        }
+    }
+
+    /// Resolves the set against the stored timings and accepted answers.
+    /// Returns the selected days in ascending order.
+    pub fn resolve(&self) -> Vec<Day> {
+        self.resolve_with(&Timings::read_from_file(), &metadata::get_all_accepted())
+    }
+
+    fn resolve_with(&self, timings: &Timings, accepted: &HashMap<Day, Vec<u8>>) -> Vec<Day> {
+        let mut days = BTreeSet::new();
+
+        for term in &self.terms {
+            match *term {
+                Term::Range(from, to) => {
+                    days.extend(all_days().filter(|d| (from..=to).contains(d)))
+                }
+                Term::Odd => days.extend(all_days().filter(|d| d.into_inner() % 2 == 1)),
+                Term::Even => days.extend(all_days().filter(|d| d.into_inner() % 2 == 0)),
+                Term::Unsolved => days.extend(all_days().filter(|d| {
+                    !is_solved(timings, *d, accepted.get(d).map_or(&[], Vec::as_slice))
+                })),
+                Term::Slowest(n) => {
+                    let mut data: Vec<_> = timings.data.iter().collect();
+                    data.sort_by(|a, b| b.total_nanos.total_cmp(&a.total_nanos));
+                    days.extend(data.iter().take(n).map(|t| t.day));
+                }
+            }
+        }
+
+        days.into_iter().collect()
+    }
+}
+
+impl From<Day> for DaySet {
+    fn from(day: Day) -> Self {
+        Self {
+            terms: vec![Term::Range(day, day)],
+        }
+    }
+}
+
+/// A day is solved when it is benched completely or both of its answers were accepted.
+pub(crate) fn is_solved(timings: &Timings, day: Day, accepted: &[u8]) -> bool {
+    timings.is_day_complete(day) || (accepted.contains(&1) && accepted.contains(&2))
+}
+
+/* -------------------------------------------------------------------------- */
+
+impl FromStr for DaySet {
+    type Err = DaySetFromStrError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let terms = s
+            .split(',')
+            .map(|term| parse_term(term.trim()).ok_or_else(|| DaySetFromStrError(term.into())))
+            .collect::<Result<_, _>>()?;
+        Ok(Self { terms })
+    }
+}
+
+fn parse_term(term: &str) -> Option<Term> {
+    match term {
+        "all" => return Some(Term::Range(day!(1), day!(25))),
+        "odd" => return Some(Term::Odd),
+        "even" => return Some(Term::Even),
+        "unsolved" => return Some(Term::Unsolved),
+        _ => {}
+    }
+
+    if let Some(n) = term.strip_prefix("slowest:") {
+        return n.parse().ok().map(Term::Slowest);
+    }
+
+    match term.split_once('-') {
+        Some((from, to)) => {
+            let from = if from.is_empty() {
+                day!(1)
+            } else {
+                from.parse().ok()?
+            };
+            let to = if to.is_empty() {
+                day!(25)
+            } else {
+                to.parse().ok()?
+            };
+            (from <= to).then_some(Term::Range(from, to))
+        }
+        None => term.parse().ok().map(|day| Term::Range(day, day)),
+    }
+}
+
+/// An error which can be returned when parsing a [`DaySet`].
+#[derive(Debug)]
+pub struct DaySetFromStrError(String);
+
+impl Error for DaySetFromStrError {}
+
+impl Display for DaySetFromStrError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(
+            f,
+            "invalid day selection `{}`, expecting days (`8`), ranges (`1-5`, `12-`), \
+            `all`, `odd`, `even`, `unsolved` or `slowest:<n>`",
+            self.0
+        )
+    }
+}
+
+/* -------------------------------------------------------------------------- */
+
+#[cfg(feature = "test_lib")]
+mod tests {
+    use super::DaySet;
+    use crate::day;
+    use crate::template::timings::{Timing, Timings};
+    use crate::template::Day;
+    use std::collections::HashMap;
+
+    fn timing(day: u8, total_nanos: f64) -> Timing {
+        Timing {
+            day: Day::new(day).unwrap(),
+            part_1: Some("1ms".into()),
+            part_2: Some("1ms".into()),
+            not_implemented: vec![],
+            peak_bytes: None,
+            total_nanos,
+        }
+    }
+
+    fn resolve(s: &str) -> Vec<u8> {
+        let timings = Timings {
+            data: vec![timing(1, 10.0), timing(2, 30.0), timing(3, 20.0)],
+        };
+        let accepted = HashMap::from([(day!(4), vec![1, 2]), (day!(5), vec![1])]);
+        s.parse::<DaySet>()
+            .unwrap()
+            .resolve_with(&timings, &accepted)
+            .into_iter()
+            .map(|d| d.into_inner())
+            .collect()
+    }
+
+    #[test]
+    fn parses_ranges() {
+        assert_eq!(
+            resolve("1-5,8,12-"),
+            vec![1, 2, 3, 4, 5, 8, 12, 13, 14, 15, 16, 17, 18, 19, 20, 21, 22, 23, 24, 25]
+        );
+        assert_eq!(resolve("1-3,8,24-"), vec![1, 2, 3, 8, 24, 25]);
+        assert_eq!(resolve("-3"), vec![1, 2, 3]);
+        assert_eq!(resolve("3,1,3"), vec![1, 3]);
+    }
+
+    #[test]
+    fn parses_keywords() {
+        assert_eq!(resolve("odd").len(), 13);
+        assert_eq!(
+            resolve("even,25"),
+            vec![2, 4, 6, 8, 10, 12, 14, 16, 18, 20, 22, 24, 25]
+        );
+        assert_eq!(resolve("all").len(), 25);
+    }
+
+    #[test]
+    fn resolves_against_progress() {
+        assert_eq!(resolve("unsolved").contains(&4), false);
+        assert_eq!(resolve("unsolved").contains(&5), true);
+        assert_eq!(resolve("unsolved").contains(&1), false);
+        assert_eq!(resolve("slowest:2"), vec![2, 3]);
+    }
+
+    #[test]
+    fn rejects_invalid_terms() {
+        for s in ["0", "26", "5-3", "slowest:x", "prime", "1,,2"] {
+            assert_eq!(s.parse::<DaySet>().is_err(), true, "{s}");
+        }
+    }
+}
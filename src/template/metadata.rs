@@ -0,0 +1,225 @@
+//! Module that records per-day puzzle information, such as the title and accepted parts, under `data/metadata`.
+use std::{collections::HashMap, fs, io::Error, path::Path, str::FromStr, time::SystemTime};
+use tinyjson::JsonValue;
+
+use crate::template::unlock::latest_event_year;
+use crate::template::Day;
+
+static METADATA_DIR_PATH: &str = "./data/metadata";
+
+fn get_metadata_path(day: Day) -> String {
+    format!("{METADATA_DIR_PATH}/{day}.json")
+}
+
+fn get_puzzle_path(day: Day) -> String {
+    format!("data/puzzles/{day}.md")
+}
+
+/// Locally recorded information about a single day.
+/// Can be serialized from / to JSON.
+#[derive(Clone, Debug, Default, PartialEq, Eq)]
+pub struct Metadata {
+    pub title: Option<String>,
+    /// Parts whose answer was accepted by the website.
+    pub accepted: Vec<u8>,
+}
+
+impl Metadata {
+    /// Dehydrate metadata to the day's JSON file.
+    pub fn store_file(&self, day: Day) -> Result<(), Error> {
+        fs::create_dir_all(METADATA_DIR_PATH)?;
+        let json = JsonValue::from(self.clone());
+        let mut file = fs::File::create(get_metadata_path(day))?;
+        json.format_to(&mut file)
+    }
+
+    /// Rehydrate metadata from the day's JSON file. If not present, returns empty metadata.
+    pub fn read_from_file(day: Day) -> Self {
+        fs::read_to_string(get_metadata_path(day))
+            .map_err(|x| x.to_string())
+            .and_then(Metadata::try_from)
+            .unwrap_or_default()
+    }
+}
+
+/// Extracts the title from a puzzle description, e.g. `Historian Hysteria` from `--- Day 1: Historian Hysteria ---`.
+pub fn extract_title(puzzle: &str) -> Option<String> {
+    let heading = puzzle
+        .lines()
+        .find(|l| l.contains("Day ") && l.contains(':'))?;
+    let title = heading
+        .split_once(':')?
+        .1
+        .replace('\\', "")
+        .trim_matches(|c: char| c == '-' || c.is_whitespace())
+        .to_string();
+    (!title.is_empty()).then_some(title)
+}
+
+/// Returns the recorded title of a day, if known.
+pub fn get_title(day: Day) -> Option<String> {
+    Metadata::read_from_file(day).title
+}
+
+/// Returns the recorded titles of all days that have one.
+pub fn get_titles() -> HashMap<Day, String> {
+    super::all_days()
+        .filter_map(|day| Some((day, get_title(day)?)))
+        .collect()
+}
+
+/// Reads the title from a downloaded puzzle description.
+pub fn read_title(puzzle_path: impl AsRef<Path>) -> Option<String> {
+    extract_title(&fs::read_to_string(puzzle_path).ok()?)
+}
+
+/// Reads the title from the day's downloaded puzzle description and records it.
+pub fn record_title(day: Day) -> Option<String> {
+    let title = read_title(get_puzzle_path(day))?;
+    let mut metadata = Metadata::read_from_file(day);
+    if metadata.title.as_ref() != Some(&title) {
+        metadata.title = Some(title.clone());
+        if let Err(e) = metadata.store_file(day) {
+            eprintln!("Failed to store metadata: {e}");
+        }
+    }
+    Some(title)
+}
+
+/// Returns the parts of a day whose answer was accepted.
+pub fn get_accepted(day: Day) -> Vec<u8> {
+    Metadata::read_from_file(day).accepted
+}
+
+/// Returns the accepted parts of all days that have at least one.
+pub fn get_all_accepted() -> HashMap<Day, Vec<u8>> {
+    super::all_days()
+        .map(|day| (day, get_accepted(day)))
+        .filter(|(_, parts)| !parts.is_empty())
+        .collect()
+}
+
+/// Records that the answer to a part was accepted.
+pub fn record_accepted(day: Day, part: u8) {
+    let mut metadata = Metadata::read_from_file(day);
+    if metadata.accepted.contains(&part) {
+        return;
+    }
+
+    metadata.accepted.push(part);
+    metadata.accepted.sort_unstable();
+    if let Err(e) = metadata.store_file(day) {
+        eprintln!("Failed to store metadata: {e}");
+    }
+}
+
+/// Returns the URL of a day's puzzle description.
+/// Without a year, links to the latest event like aoc-cli does.
+pub fn puzzle_url(day: Day, year: Option<u16>) -> String {
+    let year = year.unwrap_or_else(|| latest_event_year(SystemTime::now()));
+    format!("https://adventofcode.com/{year}/day/{}", day.into_inner())
+}
+
+/* -------------------------------------------------------------------------- */
+
+impl From<Metadata> for JsonValue {
+    fn from(value: Metadata) -> Self {
+        let mut map: HashMap<String, JsonValue> = HashMap::new();
+
+        map.insert(
+            "title".into(),
+            match value.title {
+                Some(x) => JsonValue::String(x),
+                None => JsonValue::Null,
+            },
+        );
+
+        map.insert(
+            "accepted".into(),
+            JsonValue::Array(
+                value
+                    .accepted
+                    .iter()
+                    .map(|&part| JsonValue::Number(part.into()))
+                    .collect(),
+            ),
+        );
+
+        JsonValue::Object(map)
+    }
+}
+
+impl TryFrom<String> for Metadata {
+    type Error = String;
+
+    fn try_from(value: String) -> Result<Self, Self::Error> {
+        let json = JsonValue::from_str(&value).or(Err("not valid JSON file."))?;
+
+        let json = json
+            .get::<HashMap<String, JsonValue>>()
+            .ok_or("expected JSON document to be an object.")?;
+
+        let title = match json.get("title") {
+            None => None,
+            Some(v) if v.is_null() => None,
+            Some(v) => Some(
+                v.get::<String>()
+                    .ok_or("Expected metadata.title to be null or string.")?
+                    .clone(),
+            ),
+        };
+
+        let accepted = match json.get("accepted") {
+            None => vec![],
+            Some(v) => v
+                .get::<Vec<JsonValue>>()
+                .and_then(|parts| {
+                    parts
+                        .iter()
+                        .map(|part| part.get::<f64>().map(|&x| x as u8))
+                        .collect::<Option<Vec<_>>>()
+                })
+                .ok_or("Expected metadata.accepted to be an array of numbers.")?,
+        };
+
+        Ok(Metadata { title, accepted })
+    }
+}
+
+/* -------------------------------------------------------------------------- */
+
+#[cfg(feature = "test_lib")]
+mod tests {
+    use super::{extract_title, Metadata};
+    use tinyjson::JsonValue;
+
+    #[test]
+    fn extracts_title() {
+        let puzzle = "## \\-\\-\\- Day 16: Reindeer Maze \\-\\-\\-\n\nIt's time again for the...";
+        assert_eq!(extract_title(puzzle), Some("Reindeer Maze".into()));
+
+        let puzzle = "--- Day 1: Historian Hysteria ---\nThe Chief Historian...";
+        assert_eq!(extract_title(puzzle), Some("Historian Hysteria".into()));
+    }
+
+    #[test]
+    fn handles_missing_title() {
+        assert_eq!(extract_title("no heading here"), None);
+    }
+
+    #[test]
+    fn roundtrips_json() {
+        let metadata = Metadata {
+            title: Some("Mull It Over".into()),
+            accepted: vec![1, 2],
+        };
+        let json = JsonValue::from(metadata.clone()).stringify().unwrap();
+        assert_eq!(Metadata::try_from(json).unwrap(), metadata);
+    }
+
+    #[test]
+    fn handles_empty_json() {
+        let metadata = Metadata::try_from("{}".to_string()).unwrap();
+        assert_eq!(metadata, Metadata::default());
+    }
+}
@@ -3,11 +3,16 @@ use std::{env, fs};
 pub mod aoc_cli;
 pub mod commands;
 pub mod runner;
+pub mod unlock;
 
 pub use day::*;
+pub use day_set::*;
 
 mod day;
+mod day_set;
+mod metadata;
 mod readme_benchmarks;
+mod readme_stars;
 mod run_multi;
 mod timings;
 
@@ -38,6 +43,9 @@ pub fn read_file_part(folder: &str, day: Day, part: u8) -> String {
 
 /// Creates the constant `DAY` and sets up the input and runner for each part.
 ///
+/// The same file is compiled into the library as a module of [`crate::days`], where `main` is unused.
+/// Parts without a `part_one` / `part_two` function are detected and reported as not implemented.
+/// A `fuzz_targets` function registers differential tests for `cargo fuzz-day`, see [`crate::fuzz`].
 /// The optional, second parameter (1 or 2) allows you to only run a single part of the solution.
 #[macro_export]
 macro_rules! solution {
@@ -53,14 +61,22 @@ macro_rules! solution {
 
     (@impl $day:expr, $( [$func:expr, $part:expr] )*) => {
         /// The current day.
-        const DAY: $crate::template::Day = $crate::day!($day);
+        pub const DAY: $crate::template::Day = $crate::day!($day);
 
-        #[cfg(feature = "dhat-heap")]
-        #[global_allocator]
-        static ALLOC: dhat::Alloc = dhat::Alloc;
+        // NOTE: functions defined by the solution shadow these glob-imported fallbacks.
+        #[allow(unused_imports)]
+        pub use $crate::template::runner::fallback::*;
 
+        #[allow(dead_code)]
         fn main() {
             use $crate::template::runner::*;
+            if run_fuzz(DAY, fuzz_targets) {
+                return;
+            }
+            if let Some(scale) = scale_args() {
+                $( run_scaled($func, DAY, $part, scale); )*
+                return;
+            }
             let input = $crate::template::read_file("inputs", DAY);
             $( run_part($func, &input, DAY, $part); )*
         }
@@ -1,9 +1,9 @@
 /// Module that updates the readme me with timing information.
 /// The approach taken is similar to how `aoc-readme-stars` handles this.
-use std::{fs, io};
+use std::{collections::HashMap, fs, io};
 
 use crate::template::timings::Timings;
-use crate::template::Day;
+use crate::template::{metadata, Day};
 
 static MARKER: &str = "<!--- benchmarking table --->";
 
@@ -30,8 +30,8 @@ pub fn get_path_for_bin(day: Day) -> String {
     format!("./src/bin/{day}.rs")
 }
 
-fn locate_table(readme: &str) -> Result<TablePosition, Error> {
-    let matches: Vec<_> = readme.match_indices(MARKER).collect();
+fn locate_table(readme: &str, marker: &str) -> Result<TablePosition, Error> {
+    let matches: Vec<_> = readme.match_indices(marker).collect();
 
     if matches.len() > 2 {
         return Err(Error::Parser(
@@ -52,7 +52,19 @@ fn locate_table(readme: &str) -> Result<TablePosition, Error> {
     Ok(TablePosition { pos_start, pos_end })
 }
 
-fn construct_table(prefix: &str, timings: Timings, total_millis: f64) -> String {
+/// Replaces the section enclosed by `marker` with `table`, which is expected to be enclosed by `marker` as well.
+pub fn replace_table(s: &mut String, marker: &str, table: &str) -> Result<(), Error> {
+    let positions = locate_table(s, marker)?;
+    s.replace_range(positions.pos_start..positions.pos_end, table);
+    Ok(())
+}
+
+fn construct_table(
+    prefix: &str,
+    timings: Timings,
+    total_millis: f64,
+    titles: &HashMap<Day, String>,
+) -> String {
     let header = format!("{prefix} Benchmarks");
 
     let mut lines: Vec<String> = vec![
@@ -65,13 +77,17 @@ fn construct_table(prefix: &str, timings: Timings, total_millis: f64) -> String
 
     for timing in timings.data {
         let path = get_path_for_bin(timing.day);
-        lines.push(format!(
-            "| [Day {}]({}) | `{}` | `{}` |",
-            timing.day.into_inner(),
-            path,
-            timing.part_1.unwrap_or_else(|| "-".into()),
-            timing.part_2.unwrap_or_else(|| "-".into())
-        ));
+        let [part_1, part_2] =
+            [(1, &timing.part_1), (2, &timing.part_2)].map(|(part, t)| match t {
+                Some(t) => t.clone(),
+                None if timing.not_implemented.contains(&part) => "n/a".into(),
+                None => "-".into(),
+            });
+        let label = match titles.get(&timing.day) {
+            Some(title) => format!("Day {}: {title}", timing.day.into_inner()),
+            None => format!("Day {}", timing.day.into_inner()),
+        };
+        lines.push(format!("| [{label}]({path}) | `{part_1}` | `{part_2}` |"));
     }
 
     lines.push(String::new());
@@ -81,18 +97,21 @@ fn construct_table(prefix: &str, timings: Timings, total_millis: f64) -> String
     lines.join("\n")
 }
 
-fn update_content(s: &mut String, timings: Timings, total_millis: f64) -> Result<(), Error> {
-    let positions = locate_table(s)?;
-    let table = construct_table("##", timings, total_millis);
-    s.replace_range(positions.pos_start..positions.pos_end, &table);
-    Ok(())
+fn update_content(
+    s: &mut String,
+    timings: Timings,
+    total_millis: f64,
+    titles: &HashMap<Day, String>,
+) -> Result<(), Error> {
+    let table = construct_table("##", timings, total_millis, titles);
+    replace_table(s, MARKER, &table)
 }
 
 pub fn update(timings: Timings) -> Result<(), Error> {
     let path = "README.md";
     let mut readme = String::from_utf8_lossy(&fs::read(path)?).to_string();
     let total_millis = timings.total_millis();
-    update_content(&mut readme, timings, total_millis)?;
+    update_content(&mut readme, timings, total_millis, &metadata::get_titles())?;
     fs::write(path, &readme)?;
     Ok(())
 }
@@ -101,6 +120,7 @@ pub fn update(timings: Timings) -> Result<(), Error> {
 mod tests {
     use super::{update_content, MARKER};
     use crate::{day, template::timings::Timing, template::timings::Timings};
+    use std::collections::HashMap;
 
     fn get_mock_timings() -> Timings {
         Timings {
@@ -109,18 +129,24 @@ mod tests {
                     day: day!(1),
                     part_1: Some("10ms".into()),
                     part_2: Some("20ms".into()),
+                    not_implemented: vec![],
+                    peak_bytes: None,
                     total_nanos: 3e+10,
                 },
                 Timing {
                     day: day!(2),
                     part_1: Some("30ms".into()),
                     part_2: Some("40ms".into()),
+                    not_implemented: vec![],
+                    peak_bytes: None,
                     total_nanos: 7e+10,
                 },
                 Timing {
                     day: day!(4),
                     part_1: Some("40ms".into()),
                     part_2: Some("50ms".into()),
+                    not_implemented: vec![],
+                    peak_bytes: None,
                     total_nanos: 9e+10,
                 },
             ],
@@ -131,28 +157,28 @@ mod tests {
     #[should_panic]
     fn errors_if_marker_not_present() {
         let mut s = "# readme".to_string();
-        update_content(&mut s, get_mock_timings(), 190.0).unwrap();
+        update_content(&mut s, get_mock_timings(), 190.0, &HashMap::new()).unwrap();
     }
 
     #[test]
     #[should_panic]
     fn errors_if_too_many_markers_present() {
         let mut s = format!("{} {} {}", MARKER, MARKER, MARKER);
-        update_content(&mut s, get_mock_timings(), 190.0).unwrap();
+        update_content(&mut s, get_mock_timings(), 190.0, &HashMap::new()).unwrap();
     }
 
     #[test]
     fn updates_empty_benchmarks() {
         let mut s = format!("foo\nbar\n{}{}\nbaz", MARKER, MARKER);
-        update_content(&mut s, get_mock_timings(), 190.0).unwrap();
+        update_content(&mut s, get_mock_timings(), 190.0, &HashMap::new()).unwrap();
         assert_eq!(s.contains("## Benchmarks"), true);
     }
 
     #[test]
     fn updates_existing_benchmarks() {
         let mut s = format!("foo\nbar\n{}{}\nbaz", MARKER, MARKER);
-        update_content(&mut s, get_mock_timings(), 190.0).unwrap();
-        update_content(&mut s, get_mock_timings(), 190.0).unwrap();
+        update_content(&mut s, get_mock_timings(), 190.0, &HashMap::new()).unwrap();
+        update_content(&mut s, get_mock_timings(), 190.0, &HashMap::new()).unwrap();
         assert_eq!(s.matches(MARKER).collect::<Vec<&str>>().len(), 2);
         assert_eq!(s.matches("## Benchmarks").collect::<Vec<&str>>().len(), 1);
     }
@@ -160,7 +186,7 @@ mod tests {
     #[test]
     fn format_benchmarks() {
         let mut s = format!("foo\nbar\n{}\n{}\nbaz", MARKER, MARKER);
-        update_content(&mut s, get_mock_timings(), 190.0).unwrap();
+        update_content(&mut s, get_mock_timings(), 190.0, &HashMap::new()).unwrap();
         let expected = [
             "foo",
             "bar",
@@ -180,4 +206,16 @@ mod tests {
         .join("\n");
         assert_eq!(s, expected);
     }
+
+    #[test]
+    fn format_benchmarks_with_titles() {
+        let mut s = format!("{}\n{}", MARKER, MARKER);
+        let titles = HashMap::from([(day!(2), "Red-Nosed Reports".to_string())]);
+        update_content(&mut s, get_mock_timings(), 190.0, &titles).unwrap();
+        assert_eq!(s.contains("| [Day 1](./src/bin/01.rs) |"), true);
+        assert_eq!(
+            s.contains("| [Day 2: Red-Nosed Reports](./src/bin/02.rs) |"),
+            true
+        );
+    }
 }
@@ -0,0 +1,174 @@
+//! Module that updates the readme with the locally recorded progress.
+//! The table mirrors the one written by the `advent-readme-stars` action.
+use std::{collections::HashMap, fs};
+
+use crate::template::readme_benchmarks::{replace_table, Error};
+use crate::template::{aoc_cli, metadata, Day};
+
+static MARKER: &str = "<!--- advent_readme_stars table --->";
+
+fn construct_table(
+    year: Option<u16>,
+    accepted: &HashMap<Day, Vec<u8>>,
+    titles: &HashMap<Day, String>,
+) -> String {
+    let header = match year {
+        Some(year) => format!("## {year} Results"),
+        None => "## Results".into(),
+    };
+
+    let mut lines: Vec<String> = vec![
+        MARKER.into(),
+        header,
+        String::new(),
+        "| Day | Part 1 | Part 2 |".into(),
+        "| :---: | :---: | :---: |".into(),
+    ];
+
+    let mut days: Vec<_> = accepted.keys().copied().collect();
+    days.sort_unstable();
+
+    for day in days {
+        let url = metadata::puzzle_url(day, year);
+        let [part_1, part_2] = [1, 2].map(|part| {
+            if accepted[&day].contains(&part) {
+                "⭐"
+            } else {
+                " "
+            }
+        });
+        let label = match titles.get(&day) {
+            Some(title) => format!("Day {}: {title}", day.into_inner()),
+            None => format!("Day {}", day.into_inner()),
+        };
+        lines.push(format!("| [{label}]({url}) | {part_1} | {part_2} |"));
+    }
+
+    lines.push(MARKER.into());
+    lines.join("\n")
+}
+
+fn update_content(
+    s: &mut String,
+    year: Option<u16>,
+    accepted: &HashMap<Day, Vec<u8>>,
+    titles: &HashMap<Day, String>,
+) -> Result<(), Error> {
+    let table = construct_table(year, accepted, titles);
+    if let [(start, _)] = s.match_indices(MARKER).collect::<Vec<_>>()[..] {
+        let end = end_of_action_table(s, start + MARKER.len());
+        s.replace_range(start..end, &table);
+        return Ok(());
+    }
+    replace_table(s, MARKER, &table)
+}
+
+/// Returns the end of the table that follows a single marker at `marker_end`.
+/// The `advent-readme-stars` action writes no closing marker, just a header and the table.
+/// Without a table, the header is kept, as it might belong to the rest of the readme.
+fn end_of_action_table(s: &str, marker_end: usize) -> usize {
+    let mut end = marker_end;
+    let mut offset = marker_end;
+    let mut in_table = false;
+    for (i, line) in s[marker_end..].split_inclusive('\n').enumerate() {
+        let start = offset;
+        offset += line.len();
+        let line = line.trim_end();
+        if i == 0 || line.is_empty() {
+            // the rest of the marker line, or blank lines around the header
+            if in_table {
+                break;
+            }
+            continue;
+        }
+        if line.starts_with('|') {
+            in_table = true;
+            end = start + line.len();
+        } else if in_table || !line.starts_with('#') {
+            break;
+        }
+    }
+    end
+}
+
+pub fn update(accepted: &HashMap<Day, Vec<u8>>) -> Result<(), Error> {
+    let path = "README.md";
+    let mut readme = String::from_utf8_lossy(&fs::read(path)?).to_string();
+    update_content(
+        &mut readme,
+        aoc_cli::get_year(),
+        accepted,
+        &metadata::get_titles(),
+    )?;
+    fs::write(path, &readme)?;
+    Ok(())
+}
+
+#[cfg(feature = "test_lib")]
+mod tests {
+    use super::{update_content, MARKER};
+    use crate::day;
+    use std::collections::HashMap;
+
+    fn get_mock_accepted() -> HashMap<crate::template::Day, Vec<u8>> {
+        HashMap::from([(day!(2), vec![1]), (day!(1), vec![1, 2])])
+    }
+
+    #[test]
+    #[should_panic]
+    fn errors_if_marker_not_present() {
+        let mut s = "# readme".to_string();
+        update_content(&mut s, Some(2024), &get_mock_accepted(), &HashMap::new()).unwrap();
+    }
+
+    #[test]
+    fn updates_existing_stars() {
+        let mut s = format!("foo\n{}\nbar", MARKER);
+        update_content(&mut s, Some(2024), &get_mock_accepted(), &HashMap::new()).unwrap();
+        update_content(&mut s, Some(2024), &get_mock_accepted(), &HashMap::new()).unwrap();
+        assert_eq!(s.matches(MARKER).collect::<Vec<&str>>().len(), 2);
+        assert_eq!(s.matches("## 2024 Results").collect::<Vec<&str>>().len(), 1);
+    }
+
+    #[test]
+    fn replaces_table_of_action() {
+        let mut s = [
+            "foo",
+            MARKER,
+            "## 2023 Results",
+            "",
+            "| Day | Part 1 | Part 2 |",
+            "| :---: | :---: | :---: |",
+            "| [Day 7](https://adventofcode.com/2023/day/7) | ⭐ | ⭐ |",
+            "",
+            "bar",
+        ]
+        .join("\n");
+        update_content(&mut s, Some(2024), &get_mock_accepted(), &HashMap::new()).unwrap();
+        assert_eq!(s.matches(MARKER).count(), 2);
+        assert!(!s.contains("2023"));
+        assert!(s.starts_with(&format!("foo\n{MARKER}\n## 2024 Results\n")));
+        assert!(s.ends_with(&format!("| ⭐ |   |\n{MARKER}\n\nbar")));
+    }
+
+    #[test]
+    fn format_stars() {
+        let mut s = format!("foo\n{}\nbar", MARKER);
+        let titles = HashMap::from([(day!(1), "Historian Hysteria".to_string())]);
+        update_content(&mut s, Some(2024), &get_mock_accepted(), &titles).unwrap();
+        let expected = [
+            "foo",
+            "<!--- advent_readme_stars table --->",
+            "## 2024 Results",
+            "",
+            "| Day | Part 1 | Part 2 |",
+            "| :---: | :---: | :---: |",
+            "| [Day 1: Historian Hysteria](https://adventofcode.com/2024/day/1) | ⭐ | ⭐ |",
+            "| [Day 2](https://adventofcode.com/2024/day/2) | ⭐ |   |",
+            "<!--- advent_readme_stars table --->",
+            "bar",
+        ]
+        .join("\n");
+        assert_eq!(s, expected);
+    }
+}
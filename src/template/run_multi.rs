@@ -3,7 +3,7 @@ use std::{collections::HashSet, io};
 use crate::template::{Day, ANSI_BOLD, ANSI_ITALIC, ANSI_RESET};
 
 use super::{
-    all_days,
+    all_days, metadata,
     timings::{Timing, Timings},
 };
 
@@ -21,8 +21,12 @@ pub fn run_multi(days_to_run: &HashSet<Day>, is_release: bool, is_timed: bool) -
             }
             need_space = true;
 
-            println!("{ANSI_BOLD}Day {day}{ANSI_RESET}");
-            println!("------");
+            let header = match metadata::get_title(day) {
+                Some(title) => format!("Day {day}: {title}"),
+                None => format!("Day {day}"),
+            };
+            println!("{ANSI_BOLD}{header}{ANSI_RESET}");
+            println!("{}", "-".repeat(header.chars().count()));
 
             let output = child_commands::run_solution(day, is_timed, is_release).unwrap();
 
@@ -68,8 +72,9 @@ pub fn get_path_for_bin(day: Day) -> String {
 /// This module encapsulates interaction with these binaries, both invoking them as well as parsing the timing output.
 pub mod child_commands {
     use super::{get_path_for_bin, Error};
-    use crate::template::Day;
+    use crate::template::{runner::NotImplemented, Day, ANSI_BOLD, ANSI_RESET};
     use std::{
+        fs,
         io::{BufRead, BufReader},
         path::Path,
         process::{Command, Stdio},
@@ -83,24 +88,11 @@ pub mod child_commands {
             return Ok(vec![]);
         }
 
-        let day_padded = day.to_string();
-        let mut args = vec!["run", "--quiet", "--bin", &day_padded];
-
-        if is_release {
-            args.push("--release");
-        }
-
-        if is_timed {
-            // mirror `--time` flag to child invocations.
-            args.push("--");
-            args.push("--time");
-        }
-
         // spawn child command with piped stdout/stderr.
         // forward output to stdout/stderr while grabbing stdout lines.
 
         let mut cmd = Command::new("cargo")
-            .args(&args)
+            .args(solution_args(day, is_timed, is_release))
             .stdout(Stdio::piped())
             .stderr(Stdio::piped())
             .spawn()?;
@@ -128,11 +120,58 @@ pub mod child_commands {
         Ok(output)
     }
 
+    /// Run the solution bin for a given day without forwarding its output, e.g. when drawing a terminal UI.
+    #[cfg(feature = "tui")]
+    pub fn run_solution_quiet(
+        day: Day,
+        is_timed: bool,
+        is_release: bool,
+    ) -> Result<Vec<String>, Error> {
+        if !Path::new(&get_path_for_bin(day)).exists() {
+            return Ok(vec![]);
+        }
+
+        let output = Command::new("cargo")
+            .args(solution_args(day, is_timed, is_release))
+            .stdout(Stdio::piped())
+            .stderr(Stdio::piped())
+            .output()?;
+
+        Ok(String::from_utf8_lossy(&output.stdout)
+            .lines()
+            .chain(String::from_utf8_lossy(&output.stderr).lines())
+            .map(str::to_owned)
+            .collect())
+    }
+
+    fn solution_args(day: Day, is_timed: bool, is_release: bool) -> Vec<String> {
+        let mut args = vec![
+            "run".into(),
+            "--quiet".into(),
+            "--bin".into(),
+            day.to_string(),
+        ];
+
+        if is_release {
+            args.push("--release".into());
+        }
+
+        if is_timed {
+            // mirror `--time` flag to child invocations.
+            args.push("--".into());
+            args.push("--time".into());
+        }
+
+        args
+    }
+
     pub fn parse_exec_time(output: &[String], day: Day) -> super::Timing {
         let mut timings = super::Timing {
             day,
             part_1: None,
             part_2: None,
+            not_implemented: parse_not_implemented(output),
+            peak_bytes: parse_peak_bytes(output),
             total_nanos: 0_f64,
         };
 
@@ -164,6 +203,98 @@ pub mod child_commands {
         timings
     }
 
+    /// Runs the solution bin for a given day with DHAT and returns the peak heap usage of its parts.
+    /// The output is not forwarded, as the run is only for measuring.
+    pub fn measure_memory(day: Day) -> Result<Option<u64>, Error> {
+        if !Path::new(&get_path_for_bin(day)).exists() {
+            return Ok(None);
+        }
+
+        let output = Command::new("cargo")
+            .args([
+                "run",
+                "--quiet",
+                "--profile",
+                "dhat",
+                "--features",
+                "dhat-heap",
+                "--bin",
+                &day.to_string(),
+            ])
+            .stdout(Stdio::piped())
+            .stderr(Stdio::null())
+            .output()?;
+        // the profile is of no use on its own, don't leave it behind.
+        let _ = fs::remove_file("dhat-heap.json");
+
+        let output: Vec<String> = String::from_utf8_lossy(&output.stdout)
+            .lines()
+            .map(str::to_owned)
+            .collect();
+        Ok(parse_peak_bytes(&output))
+    }
+
+    /// The largest peak heap usage reported by the runner for any part, see `--dhat`.
+    pub fn parse_peak_bytes(output: &[String]) -> Option<u64> {
+        output
+            .iter()
+            .filter_map(|l| {
+                l.strip_prefix("Part ")?
+                    .split_once(" peak heap: ")?
+                    .1
+                    .strip_suffix(" bytes")?
+                    .parse()
+                    .ok()
+            })
+            .max()
+    }
+
+    /// Parts reported by the runner as not having a solution function.
+    pub fn parse_not_implemented(output: &[String]) -> Vec<u8> {
+        output
+            .iter()
+            .filter(|l| l.ends_with(&format!("{NotImplemented}{ANSI_RESET}")))
+            .filter_map(|l| l.strip_prefix("Part ")?.split(':').next()?.parse().ok())
+            .collect()
+    }
+
+    /// Answers printed by the runner for each part. Multi-line answers are joined with newlines.
+    pub fn parse_answers(output: &[String]) -> [Option<String>; 2] {
+        let mut answers = [None, None];
+        // intermediate results are overwritten with `\r`, keep the final one.
+        let mut lines = output
+            .iter()
+            .map(|l| l.rsplit('\r').next().unwrap_or(l))
+            .peekable();
+
+        while let Some(line) = lines.next() {
+            let Some((part, value)) = line.strip_prefix("Part ").and_then(|l| l.split_once(": "))
+            else {
+                continue;
+            };
+            let index = match part {
+                "1" => 0,
+                "2" => 1,
+                _ => continue,
+            };
+
+            answers[index] = if value.starts_with('▼') {
+                let mut rows = vec![];
+                while let Some(row) = lines.next_if(|l| !l.is_empty() && !l.starts_with("Part ")) {
+                    rows.push(row);
+                }
+                Some(rows.join("\n"))
+            } else {
+                value
+                    .strip_prefix(ANSI_BOLD)
+                    .and_then(|v| v.split(ANSI_RESET).next())
+                    .map(str::to_owned)
+            };
+        }
+
+        answers
+    }
+
     fn parse_to_float(s: &str, postfix: &str) -> Option<f64> {
         s.split(postfix).next()?.parse().ok()
     }
@@ -174,7 +305,7 @@ pub mod child_commands {
             .split(" samples)")
             .next()?
             .split('(')
-            .last()?
+            .next_back()?
             .split('@')
             .next()?
             .trim();
@@ -205,7 +336,7 @@ pub mod child_commands {
 
     #[cfg(feature = "test_lib")]
     mod tests {
-        use super::parse_exec_time;
+        use super::{parse_answers, parse_exec_time, parse_peak_bytes};
 
         use crate::day;
 
@@ -239,6 +370,58 @@ pub mod child_commands {
             assert_eq!(res.part_2.unwrap(), "100ms");
         }
 
+        #[test]
+        fn parses_not_implemented_parts() {
+            let res = parse_exec_time(
+                &[
+                    "Part 1: 7 (2s @ 5 samples)".into(),
+                    "Part 2: \x1b[3mnot implemented\x1b[0m".into(),
+                    "".into(),
+                ],
+                day!(25),
+            );
+            assert_eq!(res.part_1.unwrap(), "2s");
+            assert_eq!(res.part_2.is_none(), true);
+            assert_eq!(res.not_implemented, vec![2]);
+        }
+
+        #[test]
+        fn parses_answers() {
+            let answers = parse_answers(&[
+                "Part 1: \x1b[1m42\x1b[0m\rPart 1: \x1b[1m42\x1b[0m (166.0ns)".into(),
+                "Part 2: ✖\rPart 2: ✖             ".into(),
+            ]);
+            assert_eq!(answers, [Some("42".into()), None]);
+        }
+
+        #[test]
+        fn parses_multiline_answers() {
+            let answers = parse_answers(&[
+                "Part 1: \x1b[1m6,1\x1b[0m (1.0µs)".into(),
+                "Part 2: ▼ (2.0µs)".into(),
+                "#..".into(),
+                ".#.".into(),
+                "".into(),
+            ]);
+            assert_eq!(answers, [Some("6,1".into()), Some("#..\n.#.".into())]);
+        }
+
+        #[test]
+        fn parses_peak_memory() {
+            let output = [
+                "Part 1: \x1b[1m42\x1b[0m (1.0ms)".into(),
+                "Part 1 peak heap: 2048 bytes".into(),
+                "Part 2: \x1b[1m7\x1b[0m (2.0ms)".into(),
+                "Part 2 peak heap: 65536 bytes".into(),
+            ];
+            assert_eq!(parse_peak_bytes(&output), Some(65536));
+            assert_eq!(
+                parse_answers(&output),
+                [Some("42".into()), Some("7".into())]
+            );
+            assert_eq!(parse_peak_bytes(&output[..1]), None);
+        }
+
         #[test]
         fn parses_missing_parts() {
             let res = parse_exec_time(
@@ -1,21 +1,69 @@
 /// Encapsulates code that interacts with solution functions.
+use std::any::TypeId;
 use std::fmt::Display;
 use std::hint::black_box;
 use std::io::{stdout, Write};
+use std::panic::{self, AssertUnwindSafe};
 use std::process::Output;
-use std::time::{Duration, Instant};
+use std::time::{Duration, Instant, SystemTime, UNIX_EPOCH};
 use std::{cmp, env, process};
 
+use crate::fuzz::Target;
+use crate::generate::generator_for;
 use crate::template::ANSI_BOLD;
-use crate::template::{aoc_cli, Day, ANSI_ITALIC, ANSI_RESET};
+use crate::template::{aoc_cli, metadata, read_file, Day, ANSI_ITALIC, ANSI_RESET};
 
-pub fn run_part<I: Copy, T: Display>(func: impl Fn(I) -> Option<T>, input: I, day: Day, part: u8) {
+/// Number of inputs generated per fuzz target if `--iterations` is not passed.
+const DEFAULT_FUZZ_ITERATIONS: u64 = 1000;
+
+/// The result type of a part that has no solution function.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct NotImplemented;
+
+impl Display for NotImplemented {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str("not implemented")
+    }
+}
+
+/// Stand-ins for the functions a solution does not define, see [`crate::solution`].
+pub mod fallback {
+    use super::NotImplemented;
+    use crate::fuzz::Target;
+
+    pub fn part_one(_input: &str) -> Option<NotImplemented> {
+        None
+    }
+
+    pub fn part_two(_input: &str) -> Option<NotImplemented> {
+        None
+    }
+
+    pub fn fuzz_targets() -> Vec<Target> {
+        vec![]
+    }
+}
+
+pub fn run_part<I: Copy, T: Display + 'static>(
+    func: impl Fn(I) -> Option<T>,
+    input: I,
+    day: Day,
+    part: u8,
+) {
     let part_str = format!("Part {part}");
 
-    let (result, duration, samples) =
+    if TypeId::of::<T>() == TypeId::of::<NotImplemented>() {
+        println!("{part_str}: {ANSI_ITALIC}{NotImplemented}{ANSI_RESET}");
+        return;
+    }
+
+    let (result, duration, samples, peak_bytes) =
         run_timed(func, input, |result| print_result(result, &part_str, ""));
 
     print_result(&result, &part_str, &format_duration(&duration, samples));
+    if let Some(bytes) = peak_bytes {
+        println!("{part_str} peak heap: {bytes} bytes");
+    }
 
     if let Some(result) = result {
         submit_result(result, day, part);
@@ -25,17 +73,20 @@ pub fn run_part<I: Copy, T: Display>(func: impl Fn(I) -> Option<T>, input: I, da
 /// Run a solution part. The behavior differs depending on whether we are running a release or debug build:
 ///  1. in debug, the function is executed once.
 ///  2. in release, the function is benched (approx. 1 second of execution time or 10 samples, whatever take longer.)
+///
+/// With DHAT enabled, also returns the peak heap usage of the first execution.
 fn run_timed<I: Copy, T>(
     func: impl Fn(I) -> T,
     input: I,
     hook: impl Fn(&T),
-) -> (T, Duration, u128) {
+) -> (T, Duration, u128, Option<usize>) {
     let timer = Instant::now();
-    let result = {
+    let (result, peak_bytes) = {
         #[cfg(feature = "dhat-heap")]
         let _profiler = dhat::Profiler::new_heap();
 
-        func(input)
+        let result = func(input);
+        (result, peak_heap_bytes())
     };
     let base_time = timer.elapsed();
 
@@ -47,7 +98,16 @@ fn run_timed<I: Copy, T>(
         (base_time, 1)
     };
 
-    (result, run.0, run.1)
+    (result, run.0, run.1, peak_bytes)
+}
+
+/// Peak heap usage since the DHAT profiler started, if the solution runs with `--dhat`.
+fn peak_heap_bytes() -> Option<usize> {
+    #[cfg(feature = "dhat-heap")]
+    return Some(dhat::HeapStats::get().max_bytes);
+
+    #[cfg(not(feature = "dhat-heap"))]
+    None
 }
 
 fn bench<I: Copy, T>(func: impl Fn(I) -> T, input: I, base_time: &Duration) -> (Duration, u128) {
@@ -128,6 +188,8 @@ fn print_result<T: Display>(result: &Option<T>, part: &str, duration_str: &str)
 /// Parse the arguments passed to `solve` and try to submit one part of the solution if:
 ///  1. we are in `--release` mode.
 ///  2. aoc-cli is installed.
+///
+/// Accepted answers are recorded in the day's metadata, see `cargo status`.
 fn submit_result<T: Display>(
     result: T,
     day: Day,
@@ -161,5 +223,224 @@ fn submit_result<T: Display>(
     }
 
     println!("Submitting result via aoc-cli...");
-    Some(aoc_cli::submit(day, part, &result.to_string()))
+    let output = aoc_cli::submit(day, part, &result.to_string());
+    if matches!(&output, Ok(output) if aoc_cli::is_accepted(output)) {
+        metadata::record_accepted(day, part);
+    }
+    Some(output)
+}
+
+/// Parse the arguments passed to `fuzz-day` and check the fuzz targets of a day if `--fuzz` is present.
+/// Returns `false` if the solution should run as usual.
+pub fn run_fuzz(day: Day, targets: impl FnOnce() -> Vec<Target>) -> bool {
+    let args: Vec<String> = env::args().collect();
+
+    if !args.contains(&"--fuzz".into()) {
+        return false;
+    }
+
+    let value_of = |flag: &str| -> Option<u64> {
+        let value = args
+            .iter()
+            .position(|x| x == flag)
+            .map(|i| args.get(i + 1))?;
+        match value.and_then(|x| x.parse().ok()) {
+            Some(x) => Some(x),
+            None => {
+                eprintln!("Unexpected command-line input. Format: cargo fuzz-day 1 --iterations 1000 --seed 42");
+                process::exit(1);
+            }
+        }
+    };
+
+    let iterations = value_of("--iterations").unwrap_or(DEFAULT_FUZZ_ITERATIONS);
+    let seed = value_of("--seed").unwrap_or_else(|| {
+        SystemTime::now()
+            .duration_since(UNIX_EPOCH)
+            .map_or(0, |d| d.as_nanos() as u64)
+    });
+
+    let targets = targets();
+    if targets.is_empty() {
+        eprintln!("Day {day} has no fuzz targets. Define a `fuzz_targets` function in the solution to register them.");
+        process::exit(1);
+    }
+
+    println!("Fuzzing day {day} with seed {seed}, {iterations} input(s) per target.");
+
+    for target in &targets {
+        match target.check(seed, iterations) {
+            Ok(()) => println!("{}: {ANSI_BOLD}ok{ANSI_RESET}", target.name),
+            Err(failure) => {
+                println!("{}: {ANSI_BOLD}failed{ANSI_RESET}", target.name);
+                println!("{failure}");
+                process::exit(1);
+            }
+        }
+    }
+
+    true
+}
+
+/* -------------------------------------------------------------------------- */
+
+/// Settings of a scaling benchmark, see [`scale_args`].
+#[derive(Debug, Clone, Copy)]
+pub struct Scale {
+    /// Number of input sizes, each one twice as large as the previous one.
+    pub steps: u32,
+    /// Use prefixes of the real input instead of generated inputs.
+    pub truncate: bool,
+}
+
+/// Parse the arguments passed to `time --scale`.
+/// Returns `None` if the solution should run as usual.
+pub fn scale_args() -> Option<Scale> {
+    let args: Vec<String> = env::args().collect();
+    let index = args.iter().position(|x| x == "--scale")?;
+
+    match args.get(index + 1).and_then(|x| x.parse().ok()) {
+        Some(steps) if steps >= 2 => Some(Scale {
+            steps,
+            truncate: args.contains(&"--truncate".into()),
+        }),
+        _ => {
+            eprintln!("Unexpected command-line input. Format: cargo time 1 --scale 5");
+            process::exit(1);
+        }
+    }
+}
+
+/// Inputs of increasing size, the largest one as large as the real input.
+/// Returns the unit of the sizes along with `(size, input)` pairs.
+fn scaled_inputs(day: Day, scale: Scale) -> (&'static str, Vec<(usize, String)>) {
+    let shrink = |size: usize, step: u32| size >> (scale.steps - 1 - step).min(usize::BITS - 1);
+    let mut inputs: Vec<(usize, String)> = vec![];
+
+    if scale.truncate {
+        let input = read_file("inputs", day);
+        let lines: Vec<&str> = input.lines().collect();
+        for step in 0..scale.steps {
+            let size = shrink(lines.len(), step).max(1);
+            inputs.push((size, lines[..size].join("\n") + "\n"));
+        }
+        inputs.dedup_by_key(|(size, _)| *size);
+        return ("lines", inputs);
+    }
+
+    let generator = generator_for(day);
+    for step in 0..scale.steps {
+        let size = shrink(generator.default_size, step).max(generator.min_size);
+        inputs.push((size, generator.generate(size, u64::from(step))));
+    }
+    inputs.dedup_by_key(|(size, _)| *size);
+    (generator.size, inputs)
+}
+
+/// Fits `time = c * size^k` to the measurements by least squares on their logarithms and returns `k`.
+fn growth_exponent(measurements: &[(usize, Duration)]) -> Option<f64> {
+    let points: Vec<(f64, f64)> = measurements
+        .iter()
+        .map(|(size, time)| ((*size as f64).ln(), time.as_secs_f64().max(1e-9).ln()))
+        .collect();
+
+    let n = points.len() as f64;
+    let mean_x = points.iter().map(|p| p.0).sum::<f64>() / n;
+    let mean_y = points.iter().map(|p| p.1).sum::<f64>() / n;
+    let covariance: f64 = points.iter().map(|p| (p.0 - mean_x) * (p.1 - mean_y)).sum();
+    let variance: f64 = points.iter().map(|p| (p.0 - mean_x).powi(2)).sum();
+
+    (variance > 0.0).then(|| covariance / variance)
+}
+
+/// Times a single input for about 200ms.
+fn measure<T>(func: impl Fn(&str) -> T, input: &str) -> Duration {
+    let timer = Instant::now();
+    black_box(func(black_box(input)));
+    let base_time = timer.elapsed();
+
+    let iterations =
+        (Duration::from_millis(200).as_nanos() / cmp::max(base_time.as_nanos(), 10)).clamp(1, 1000);
+    let timers: Vec<Duration> = (0..iterations)
+        .map(|_| {
+            let timer = Instant::now();
+            black_box(func(black_box(input)));
+            timer.elapsed()
+        })
+        .collect();
+
+    #[allow(clippy::cast_possible_truncation)]
+    Duration::from_nanos(average_duration(&timers) as u64)
+}
+
+/// Times a solution part across inputs of increasing size and reports how its execution time grows.
+pub fn run_scaled<T: Display + 'static>(
+    func: impl Fn(&str) -> Option<T>,
+    day: Day,
+    part: u8,
+    scale: Scale,
+) {
+    let part_str = format!("Part {part}");
+
+    if TypeId::of::<T>() == TypeId::of::<NotImplemented>() {
+        println!("{part_str}: {ANSI_ITALIC}{NotImplemented}{ANSI_RESET}");
+        return;
+    }
+
+    let (unit, inputs) = scaled_inputs(day, scale);
+    println!("{ANSI_BOLD}{part_str}{ANSI_RESET} ({unit})");
+
+    // a panic on one input size is reported, and the curve is fitted to the other sizes.
+    let measurements: Vec<(usize, Duration)> = inputs
+        .iter()
+        .filter_map(|(size, input)| {
+            match panic::catch_unwind(AssertUnwindSafe(|| measure(&func, input))) {
+                Ok(time) => {
+                    println!("{size:>10}  {time:.1?}");
+                    Some((*size, time))
+                }
+                Err(_) => {
+                    println!("{size:>10}  {ANSI_ITALIC}panicked{ANSI_RESET}");
+                    None
+                }
+            }
+        })
+        .collect();
+
+    match growth_exponent(&measurements) {
+        Some(k) => println!("{ANSI_ITALIC}time grows with {unit}^{k:.2}{ANSI_RESET}"),
+        None if measurements.len() < inputs.len() => {
+            println!("{ANSI_ITALIC}too few sizes ran without panicking to fit a curve{ANSI_RESET}");
+        }
+        None => println!("{ANSI_ITALIC}not enough distinct sizes to fit a curve{ANSI_RESET}"),
+    }
+}
+
+/* -------------------------------------------------------------------------- */
+
+#[cfg(feature = "test_lib")]
+mod tests {
+    use super::growth_exponent;
+    use std::time::Duration;
+
+    fn fit(f: impl Fn(f64) -> f64) -> f64 {
+        let measurements: Vec<_> = [100, 200, 400, 800, 1600]
+            .into_iter()
+            .map(|size| (size, Duration::from_secs_f64(f(size as f64) * 1e-9)))
+            .collect();
+        growth_exponent(&measurements).unwrap()
+    }
+
+    #[test]
+    fn fits_growth_exponent() {
+        assert_eq!((fit(|n| 5.0 * n) - 1.0).abs() < 0.01, true);
+        assert_eq!((fit(|n| n * n) - 2.0).abs() < 0.01, true);
+        assert_eq!((fit(|n| n * n.ln()) - 1.0).abs() < 0.25, true);
+    }
+
+    #[test]
+    fn needs_distinct_sizes() {
+        let measurements = [(10, Duration::from_millis(1)); 3];
+        assert_eq!(growth_exponent(&measurements), None);
+    }
 }
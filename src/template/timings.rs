@@ -11,9 +11,40 @@ pub struct Timing {
     pub day: Day,
     pub part_1: Option<String>,
     pub part_2: Option<String>,
+    /// Parts that have no solution function, see [`crate::solution`].
+    pub not_implemented: Vec<u8>,
+    /// Peak heap usage of the day's parts in bytes, measured with DHAT by `cargo time --memory`.
+    pub peak_bytes: Option<u64>,
     pub total_nanos: f64,
 }
 
+impl Timing {
+    /// Whether a part was benched or deliberately left without a solution function.
+    pub fn is_part_complete(&self, part: u8) -> bool {
+        let timing = match part {
+            1 => &self.part_1,
+            2 => &self.part_2,
+            _ => return false,
+        };
+        timing.is_some() || self.not_implemented.contains(&part)
+    }
+}
+
+/// Formats a number of bytes with a binary unit, e.g. `1.5 KiB`.
+pub fn format_bytes(bytes: u64) -> String {
+    const UNITS: [&str; 4] = ["KiB", "MiB", "GiB", "TiB"];
+    if bytes < 1024 {
+        return format!("{bytes} B");
+    }
+    let mut value = bytes as f64 / 1024.0;
+    let mut unit = 0;
+    while value >= 1024.0 && unit < UNITS.len() - 1 {
+        value /= 1024.0;
+        unit += 1;
+    }
+    format!("{value:.1} {}", UNITS[unit])
+}
+
 /// Represents benchmark times for a set of days.
 /// Can be serialized from / to JSON.
 #[derive(Clone, Debug, Default)]
@@ -42,7 +73,16 @@ impl Timings {
         let mut data: Vec<Timing> = vec![];
 
         for timing in &new.data {
-            data.push(timing.clone());
+            let mut timing = timing.clone();
+            // a run without `--memory` keeps the memory measured before.
+            if timing.peak_bytes.is_none() {
+                timing.peak_bytes = self
+                    .data
+                    .iter()
+                    .find(|t| t.day == timing.day)
+                    .and_then(|t| t.peak_bytes);
+            }
+            data.push(timing);
         }
 
         for timing in &self.data {
@@ -51,7 +91,7 @@ impl Timings {
             }
         }
 
-        data.sort_unstable_by(|a, b| a.day.cmp(&b.day));
+        data.sort_unstable_by_key(|a| a.day);
         Timings { data }
     }
 
@@ -60,10 +100,12 @@ impl Timings {
         self.data.iter().map(|x| x.total_nanos).sum::<f64>() / 1_000_000_f64
     }
 
+    /// A day is complete when every part is either benched or not implemented.
+    /// Parts that return `None` keep a day incomplete.
     pub fn is_day_complete(&self, day: Day) -> bool {
         self.data
             .iter()
-            .any(|t| t.day == day && t.part_1.is_some() && t.part_2.is_some())
+            .any(|t| t.day == day && t.is_part_complete(1) && t.is_part_complete(2))
     }
 }
 
@@ -133,6 +175,25 @@ impl From<&Timing> for JsonValue {
             },
         );
 
+        map.insert(
+            "not_implemented".into(),
+            JsonValue::Array(
+                value
+                    .not_implemented
+                    .iter()
+                    .map(|&part| JsonValue::Number(part.into()))
+                    .collect(),
+            ),
+        );
+
+        map.insert(
+            "peak_bytes".into(),
+            match value.peak_bytes {
+                Some(x) => JsonValue::Number(x as f64),
+                None => JsonValue::Null,
+            },
+        );
+
         JsonValue::Object(map)
     }
 }
@@ -166,10 +227,37 @@ impl TryFrom<&JsonValue> for Timing {
             .and_then(|v| v.get::<f64>().copied())
             .ok_or("Expected timing.total_nanos to be a number.")?;
 
+        // NOTE: timings stored before this key existed have every part implemented.
+        let not_implemented = match json.get("not_implemented") {
+            None => vec![],
+            Some(v) => v
+                .get::<Vec<JsonValue>>()
+                .and_then(|parts| {
+                    parts
+                        .iter()
+                        .map(|part| part.get::<f64>().map(|&x| x as u8))
+                        .collect::<Option<Vec<_>>>()
+                })
+                .ok_or("Expected timing.not_implemented to be an array of numbers.")?,
+        };
+
+        // NOTE: memory is only recorded by `cargo time --memory`.
+        let peak_bytes = match json.get("peak_bytes") {
+            None => None,
+            Some(v) if v.is_null() => None,
+            Some(v) => Some(
+                v.get::<f64>()
+                    .map(|&x| x as u64)
+                    .ok_or("Expected timing.peak_bytes to be null or a number.")?,
+            ),
+        };
+
         Ok(Timing {
             day,
             part_1: part_1.cloned(),
             part_2: part_2.cloned(),
+            not_implemented,
+            peak_bytes,
             total_nanos,
         })
     }
@@ -181,7 +269,7 @@ impl TryFrom<&JsonValue> for Timing {
 mod tests {
     use crate::day;
 
-    use super::{Timing, Timings};
+    use super::{format_bytes, Timing, Timings};
 
     fn get_mock_timings() -> Timings {
         Timings {
@@ -190,24 +278,37 @@ mod tests {
                     day: day!(1),
                     part_1: Some("10ms".into()),
                     part_2: Some("20ms".into()),
+                    not_implemented: vec![],
+                    peak_bytes: None,
                     total_nanos: 3e+10,
                 },
                 Timing {
                     day: day!(2),
                     part_1: Some("30ms".into()),
                     part_2: Some("40ms".into()),
+                    not_implemented: vec![],
+                    peak_bytes: None,
                     total_nanos: 7e+10,
                 },
                 Timing {
                     day: day!(4),
                     part_1: Some("40ms".into()),
                     part_2: None,
+                    not_implemented: vec![],
+                    peak_bytes: None,
                     total_nanos: 4e+10,
                 },
             ],
         }
     }
 
+    #[test]
+    fn formats_bytes() {
+        assert_eq!(format_bytes(512), "512 B");
+        assert_eq!(format_bytes(1536), "1.5 KiB");
+        assert_eq!(format_bytes(3 * 1024 * 1024), "3.0 MiB");
+    }
+
     mod deserialization {
         use crate::{day, template::timings::Timings};
 
@@ -223,6 +324,20 @@ mod tests {
             assert_eq!(timing.total_nanos, 1_000_000_000_f64);
         }
 
+        #[test]
+        fn handles_not_implemented_json_timings() {
+            let json = r#"{ "data": [{ "day": "25", "part_1": "1ms", "part_2": null, "not_implemented": [2], "total_nanos": 1000000 }] }"#.to_string();
+            let timings = Timings::try_from(json).unwrap();
+            assert_eq!(timings.data[0].not_implemented, vec![2]);
+        }
+
+        #[test]
+        fn handles_memory_json_timings() {
+            let json = r#"{ "data": [{ "day": "06", "part_1": "1ms", "part_2": "2ms", "peak_bytes": 2048, "total_nanos": 3000000 }] }"#.to_string();
+            let timings = Timings::try_from(json).unwrap();
+            assert_eq!(timings.data[0].peak_bytes, Some(2048));
+        }
+
         #[test]
         fn handles_empty_timings() {
             let json = r#"{ "data": [] }"#.to_string();
@@ -281,11 +396,13 @@ mod tests {
                     day: day!(1),
                     part_1: Some("1ms".into()),
                     part_2: Some("2ms".into()),
+                    not_implemented: vec![],
+                    peak_bytes: None,
                     total_nanos: 3_000_000_000_f64,
                 }],
             };
 
-            assert_eq!(timings.is_day_complete(&day!(1)), true);
+            assert_eq!(timings.is_day_complete(day!(1)), true);
         }
 
         #[test]
@@ -295,11 +412,29 @@ mod tests {
                     day: day!(1),
                     part_1: Some("1ms".into()),
                     part_2: None,
+                    not_implemented: vec![],
+                    peak_bytes: None,
                     total_nanos: 1_000_000_000_f64,
                 }],
             };
 
-            assert_eq!(timings.is_day_complete(&day!(1)), false);
+            assert_eq!(timings.is_day_complete(day!(1)), false);
+        }
+
+        #[test]
+        fn handles_not_implemented_parts() {
+            let timings = Timings {
+                data: vec![Timing {
+                    day: day!(25),
+                    part_1: Some("1ms".into()),
+                    part_2: None,
+                    not_implemented: vec![2],
+                    peak_bytes: None,
+                    total_nanos: 1_000_000_000_f64,
+                }],
+            };
+
+            assert_eq!(timings.is_day_complete(day!(25)), true);
         }
 
         #[test]
@@ -309,11 +444,13 @@ mod tests {
                     day: day!(1),
                     part_1: None,
                     part_2: None,
+                    not_implemented: vec![],
+                    peak_bytes: None,
                     total_nanos: 0.0,
                 }],
             };
 
-            assert_eq!(timings.is_day_complete(&day!(1)), false);
+            assert_eq!(timings.is_day_complete(day!(1)), false);
         }
     }
 
@@ -333,6 +470,8 @@ mod tests {
                     day: day!(3),
                     part_1: None,
                     part_2: None,
+                    not_implemented: vec![],
+                    peak_bytes: None,
                     total_nanos: 0_f64,
                 }],
             };
@@ -353,6 +492,8 @@ mod tests {
                     day: day!(2),
                     part_1: None,
                     part_2: None,
+                    not_implemented: vec![],
+                    peak_bytes: None,
                     total_nanos: 0_f64,
                 }],
             };
@@ -365,6 +506,25 @@ mod tests {
             assert_eq!(merged.data[2].day, day!(4));
         }
 
+        #[test]
+        fn keeps_measured_memory() {
+            let mut timings = get_mock_timings();
+            timings.data[1].peak_bytes = Some(4096);
+            let other = Timings {
+                data: vec![Timing {
+                    day: day!(2),
+                    part_1: Some("1ms".into()),
+                    part_2: Some("2ms".into()),
+                    not_implemented: vec![],
+                    peak_bytes: None,
+                    total_nanos: 3e+6,
+                }],
+            };
+            let merged = timings.merge(&other);
+            assert_eq!(merged.data[1].total_nanos, 3e+6);
+            assert_eq!(merged.data[1].peak_bytes, Some(4096));
+        }
+
         #[test]
         fn handles_empty_timings() {
             let timings = Timings::default();
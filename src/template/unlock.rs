@@ -0,0 +1,216 @@
+//! Module that computes when puzzles unlock. Puzzles unlock at midnight EST (UTC-5) on each day of advent.
+use std::cell::Cell;
+use std::thread;
+use std::time::{Duration, SystemTime, UNIX_EPOCH};
+
+use crate::template::{all_days, aoc_cli, Day};
+
+/// Hour (in UTC) at which a puzzle unlocks.
+const UNLOCK_HOUR_UTC: u64 = 5;
+
+const SECONDS_PER_DAY: u64 = 24 * 60 * 60;
+
+/// A source of the current time.
+/// Waiting goes through the clock as well, so that a fake clock can be used in tests.
+pub trait Clock {
+    fn now(&self) -> SystemTime;
+    fn sleep(&self, duration: Duration);
+}
+
+/// The real wall clock.
+pub struct SystemClock;
+
+impl Clock for SystemClock {
+    fn now(&self) -> SystemTime {
+        SystemTime::now()
+    }
+
+    fn sleep(&self, duration: Duration) {
+        thread::sleep(duration);
+    }
+}
+
+/// A clock that only advances when slept on.
+pub struct FakeClock {
+    now: Cell<SystemTime>,
+}
+
+impl FakeClock {
+    pub fn new(now: SystemTime) -> Self {
+        Self {
+            now: Cell::new(now),
+        }
+    }
+}
+
+impl Clock for FakeClock {
+    fn now(&self) -> SystemTime {
+        self.now.get()
+    }
+
+    fn sleep(&self, duration: Duration) {
+        self.now.set(self.now.get() + duration);
+    }
+}
+
+/* -------------------------------------------------------------------------- */
+
+/// Days since the unix epoch for a date in the gregorian calendar.
+/// See: <http://howardhinnant.github.io/date_algorithms.html#days_from_civil>
+fn days_from_civil(year: u64, month: u64, day: u64) -> u64 {
+    let year = if month <= 2 { year - 1 } else { year };
+    let era = year / 400;
+    let year_of_era = year - era * 400;
+    let day_of_year = (153 * ((month + 9) % 12) + 2) / 5 + day - 1;
+    let day_of_era = year_of_era * 365 + year_of_era / 4 - year_of_era / 100 + day_of_year;
+    era * 146_097 + day_of_era - 719_468
+}
+
+/// Returns the moment the puzzle of `day` unlocks in `year`.
+pub fn unlock_time(year: u16, day: Day) -> SystemTime {
+    let days = days_from_civil(year.into(), 12, day.into_inner().into());
+    UNIX_EPOCH + Duration::from_secs(days * SECONDS_PER_DAY + UNLOCK_HOUR_UTC * 3600)
+}
+
+/// Returns the next day of `year` to unlock after `now`, or `None` if all puzzles are unlocked.
+pub fn next_unlock(year: u16, now: SystemTime) -> Option<(Day, SystemTime)> {
+    all_days()
+        .map(|day| (day, unlock_time(year, day)))
+        .find(|(_, unlock)| *unlock > now)
+}
+
+/// Returns the year of the latest event that has started at `now`.
+/// This is the year aoc-cli falls back to when `AOC_YEAR` is not set.
+pub fn latest_event_year(now: SystemTime) -> u16 {
+    let secs = now.duration_since(UNIX_EPOCH).unwrap_or_default().as_secs();
+    // counting 365-day years overshoots, so step back until the event has started
+    let mut year = 1971 + (secs / (365 * SECONDS_PER_DAY)) as u16;
+    while unlock_time(year, Day::new(1).unwrap()) > now {
+        year -= 1;
+    }
+    year
+}
+
+/// Formats a remaining duration, e.g. `2d 03:04:05`.
+pub fn format_countdown(remaining: Duration) -> String {
+    let secs = remaining.as_secs();
+    let (days, secs) = (secs / SECONDS_PER_DAY, secs % SECONDS_PER_DAY);
+    let time = format!("{:02}:{:02}:{:02}", secs / 3600, secs / 60 % 60, secs % 60);
+    if days > 0 {
+        format!("{days}d {time}")
+    } else {
+        time
+    }
+}
+
+/// Describes the time left until the next puzzle of `AOC_YEAR` unlocks, e.g. `Day 04 unlocks in 03:04:05`.
+pub fn countdown() -> Option<String> {
+    let now = SystemClock.now();
+    let (day, unlock) = next_unlock(aoc_cli::get_year()?, now)?;
+    let remaining = unlock.duration_since(now).ok()?;
+    Some(format!(
+        "Day {day} unlocks in {}",
+        format_countdown(remaining)
+    ))
+}
+
+/// Sleeps until the next puzzle of `year` unlocks, calling `tick` with the remaining time about once a second.
+/// Returns the unlocked day, or `None` if all puzzles of `year` are unlocked already.
+pub fn wait_for_next_unlock(
+    clock: &impl Clock,
+    year: u16,
+    mut tick: impl FnMut(Day, Duration),
+) -> Option<Day> {
+    let (day, unlock) = next_unlock(year, clock.now())?;
+
+    while let Ok(remaining) = unlock.duration_since(clock.now()) {
+        if remaining.is_zero() {
+            break;
+        }
+        tick(day, remaining);
+        clock.sleep(remaining.min(Duration::from_secs(1)));
+    }
+
+    Some(day)
+}
+
+/* -------------------------------------------------------------------------- */
+
+#[cfg(feature = "test_lib")]
+mod tests {
+    use super::{
+        format_countdown, latest_event_year, next_unlock, unlock_time, wait_for_next_unlock, Clock,
+        FakeClock,
+    };
+    use crate::day;
+    use std::time::{Duration, UNIX_EPOCH};
+
+    #[test]
+    fn computes_unlock_time() {
+        let unlock = unlock_time(2024, day!(1));
+        assert_eq!(unlock, UNIX_EPOCH + Duration::from_secs(1_733_029_200));
+
+        let unlock = unlock_time(2015, day!(25));
+        assert_eq!(unlock, UNIX_EPOCH + Duration::from_secs(1_451_019_600));
+    }
+
+    #[test]
+    fn finds_next_unlock() {
+        let now = unlock_time(2024, day!(3)) + Duration::from_secs(60);
+        assert_eq!(next_unlock(2024, now).map(|x| x.0), Some(day!(4)));
+
+        let now = unlock_time(2024, day!(3)) - Duration::from_secs(60);
+        assert_eq!(next_unlock(2024, now).map(|x| x.0), Some(day!(3)));
+
+        let now = unlock_time(2024, day!(25));
+        assert_eq!(next_unlock(2024, now), None);
+    }
+
+    #[test]
+    fn finds_latest_event() {
+        let unlock = unlock_time(2024, day!(1));
+        assert_eq!(latest_event_year(unlock), 2024);
+        assert_eq!(latest_event_year(unlock - Duration::from_secs(1)), 2023);
+        let new_year = UNIX_EPOCH + Duration::from_secs(1_735_689_600);
+        assert_eq!(latest_event_year(new_year), 2024);
+    }
+
+    #[test]
+    fn formats_countdown() {
+        assert_eq!(format_countdown(Duration::from_secs(59)), "00:00:59");
+        assert_eq!(format_countdown(Duration::from_secs(3_723)), "01:02:03");
+        assert_eq!(
+            format_countdown(Duration::from_secs(180_245)),
+            "2d 02:04:05"
+        );
+    }
+
+    #[test]
+    fn waits_with_fake_clock() {
+        let unlock = unlock_time(2024, day!(10));
+        let clock = FakeClock::new(unlock - Duration::from_millis(3_500));
+
+        let mut ticks = vec![];
+        let day = wait_for_next_unlock(&clock, 2024, |day, remaining| {
+            ticks.push((day, remaining.as_millis()))
+        });
+
+        assert_eq!(day, Some(day!(10)));
+        assert_eq!(clock.now(), unlock);
+        assert_eq!(
+            ticks,
+            vec![
+                (day!(10), 3_500),
+                (day!(10), 2_500),
+                (day!(10), 1_500),
+                (day!(10), 500)
+            ]
+        );
+    }
+
+    #[test]
+    fn does_not_wait_after_advent() {
+        let clock = FakeClock::new(unlock_time(2024, day!(25)));
+        assert_eq!(wait_for_next_unlock(&clock, 2024, |_, _| {}), None);
+    }
+}
@@ -0,0 +1,173 @@
+//! Disjoint sets with near-constant time union and lookup.
+//!
+//! Elements are the numbers `0..len`. [`first_blocking`] uses the sets to find out when a growing
+//! set of obstacles cuts a grid in two, by removing the obstacles in reverse order.
+use crate::grid::{Grid, Pos};
+use crate::point::Dir4;
+
+#[derive(Debug, Clone)]
+pub struct UnionFind {
+    parent: Vec<usize>,
+    rank: Vec<u8>,
+    sets: usize,
+}
+
+impl UnionFind {
+    /// Creates `len` singleton sets.
+    pub fn new(len: usize) -> Self {
+        Self {
+            parent: (0..len).collect(),
+            rank: vec![0; len],
+            sets: len,
+        }
+    }
+
+    pub fn len(&self) -> usize {
+        self.parent.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.parent.is_empty()
+    }
+
+    /// Returns the number of disjoint sets.
+    pub fn set_count(&self) -> usize {
+        self.sets
+    }
+
+    /// Returns the representative of the set containing `x`.
+    pub fn find(&mut self, x: usize) -> usize {
+        let mut root = x;
+        while self.parent[root] != root {
+            root = self.parent[root];
+        }
+        // path compression
+        let mut current = x;
+        while self.parent[current] != root {
+            current = std::mem::replace(&mut self.parent[current], root);
+        }
+        root
+    }
+
+    /// Merges the sets containing `a` and `b`. Returns false if they already were the same set.
+    pub fn union(&mut self, a: usize, b: usize) -> bool {
+        let (a, b) = (self.find(a), self.find(b));
+        if a == b {
+            return false;
+        }
+        let (low, high) = if self.rank[a] < self.rank[b] {
+            (a, b)
+        } else {
+            (b, a)
+        };
+        self.parent[low] = high;
+        if self.rank[low] == self.rank[high] {
+            self.rank[high] += 1;
+        }
+        self.sets -= 1;
+        true
+    }
+
+    pub fn connected(&mut self, a: usize, b: usize) -> bool {
+        self.find(a) == self.find(b)
+    }
+}
+
+/* -------------------------------------------------------------------------- */
+
+/// Returns the index of the first of `obstacles` that, together with the ones before it,
+/// disconnects `start` from `end` in a `width` x `height` grid with orthogonal moves.
+/// Returns `None` if they stay connected after all obstacles are placed, or if `start` or `end`
+/// is outside of the grid.
+///
+/// Runs in a single pass: all obstacles are placed, then removed again from the last one, until
+/// the two positions connect.
+pub fn first_blocking(
+    width: usize,
+    height: usize,
+    obstacles: &[Pos],
+    start: Pos,
+    end: Pos,
+) -> Option<usize> {
+    // index of the first obstacle on each cell, the cell is free before it
+    let mut blocked = Grid::filled(width, height, None);
+    if blocked.get(start).is_none() || blocked.get(end).is_none() {
+        return None;
+    }
+    for (i, &pos) in obstacles.iter().enumerate() {
+        if let Some(cell @ None) = blocked.get_mut(pos) {
+            *cell = Some(i);
+        }
+    }
+
+    let index = |(row, column): Pos| row * width + column;
+    let mut sets = UnionFind::new(width * height);
+    let open = |sets: &mut UnionFind, blocked: &Grid<Option<usize>>, pos: Pos| {
+        for dir in Dir4::ALL {
+            if let Some(next) = blocked
+                .step(pos, dir)
+                .filter(|next| blocked[*next].is_none())
+            {
+                sets.union(index(pos), index(next));
+            }
+        }
+    };
+    for pos in blocked.positions() {
+        if blocked[pos].is_none() {
+            open(&mut sets, &blocked, pos);
+        }
+    }
+
+    let connected = |sets: &mut UnionFind, blocked: &Grid<Option<usize>>| {
+        blocked[start].is_none()
+            && blocked[end].is_none()
+            && sets.connected(index(start), index(end))
+    };
+    if connected(&mut sets, &blocked) {
+        return None;
+    }
+    for (i, &pos) in obstacles.iter().enumerate().rev() {
+        if blocked.get(pos) != Some(&Some(i)) {
+            // out of bounds, or the cell stays blocked by an earlier obstacle
+            continue;
+        }
+        blocked[pos] = None;
+        open(&mut sets, &blocked, pos);
+        if connected(&mut sets, &blocked) {
+            return Some(i);
+        }
+    }
+    // without obstacles, every two cells of the grid are connected
+    None
+}
+
+/* -------------------------------------------------------------------------- */
+
+#[cfg(feature = "test_lib")]
+mod tests {
+    use super::{first_blocking, UnionFind};
+
+    #[test]
+    fn merges_sets() {
+        let mut sets = UnionFind::new(5);
+        assert_eq!(sets.set_count(), 5);
+        assert_eq!(sets.union(0, 1), true);
+        assert_eq!(sets.union(3, 4), true);
+        assert_eq!(sets.union(1, 0), false);
+        assert_eq!(sets.connected(0, 4), false);
+        assert_eq!(sets.union(1, 4), true);
+        assert_eq!(sets.connected(0, 3), true);
+        assert_eq!(sets.set_count(), 2);
+    }
+
+    #[test]
+    fn finds_the_first_blocking_obstacle() {
+        // a wall across the middle row of a 3x3 grid, with a repeated and an outside obstacle
+        let obstacles = [(1, 0), (1, 0), (5, 5), (1, 2), (1, 1), (0, 1)];
+        assert_eq!(first_blocking(3, 3, &obstacles, (0, 0), (2, 0)), Some(4));
+        assert_eq!(first_blocking(3, 3, &obstacles[..4], (0, 0), (2, 0)), None);
+        assert_eq!(first_blocking(3, 3, &obstacles, (0, 0), (0, 2)), Some(5));
+        assert_eq!(first_blocking(3, 3, &[(0, 0)], (0, 0), (2, 2)), Some(0));
+        assert_eq!(first_blocking(3, 3, &obstacles, (0, 0), (3, 0)), None);
+    }
+}